@@ -0,0 +1,40 @@
+// Generates one test per puzzle example found in `data/`.
+//
+// Every `data/day_NN_example_K.answers` sidecar becomes a `day_NN_example_K`
+// test in `tests/examples.rs`, so new examples are picked up without writing
+// any test code.
+
+use std::env;
+use std::fs::{read_dir, write};
+use std::path::Path;
+
+fn main() {
+    let data_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("data");
+    println!("cargo:rerun-if-changed={}", data_dir.display());
+
+    let mut examples = read_dir(&data_dir)
+        .map(|entries| {
+            entries
+                .filter_map(|entry| entry.ok())
+                .filter_map(|entry| entry.file_name().into_string().ok())
+                .filter_map(|name| {
+                    let name = name.strip_prefix("day_")?.strip_suffix(".answers")?;
+                    let (day, index) = name.split_once("_example_")?;
+                    Some((day.parse::<u8>().ok()?, index.parse::<u8>().ok()?))
+                })
+                .collect::<Vec<(u8, u8)>>()
+        })
+        .unwrap_or_default();
+    examples.sort();
+
+    let tests = examples
+        .iter()
+        .map(|(day, index)| {
+            format!(
+                "#[test]\nfn day_{day:02}_example_{index}() {{\n    aoc2022::examples::assert_example({day}, {index});\n}}\n"
+            )
+        })
+        .collect::<String>();
+    let out_dir = env::var("OUT_DIR").expect("Cargo sets OUT_DIR");
+    write(Path::new(&out_dir).join("example_tests.rs"), tests).expect("Writing example tests");
+}
@@ -0,0 +1,172 @@
+// Answers ledger
+//
+// Known-good answers for our real inputs live in `data/answers.txt`, one
+// `day part answer` per line. Multi-line answers are stored with `\n`
+// escapes. Lines starting with `#` are comments. A `day part` line without an
+// answer is a placeholder for a day that isn't solved yet.
+
+use crate::input::{data_dir, load_input, InputError};
+use crate::Answer;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs::write;
+use std::path::{Path, PathBuf};
+
+const HEADER: &str = "# Known-good answers for data/day_NN_input.txt: day part answer";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail(String),
+    Unknown,
+    // No answer to check: the part panicked or couldn't parse.
+    Error,
+    Timeout,
+}
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "PASS"),
+            Verdict::Fail(_) => write!(f, "FAIL"),
+            Verdict::Unknown => write!(f, "UNKNOWN"),
+            Verdict::Error => write!(f, "FAIL"),
+            Verdict::Timeout => write!(f, "TIMEOUT"),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Ledger {
+    answers: BTreeMap<(u8, u8), String>,
+    comments: Vec<String>,
+}
+impl Ledger {
+    pub fn path() -> PathBuf {
+        data_dir().join("answers.txt")
+    }
+    // A missing ledger is just an empty one.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, InputError> {
+        let path = path.as_ref();
+        let text = match load_input(path) {
+            Ok(text) => text,
+            Err(InputError::NotFound(_)) | Err(InputError::Empty(_)) => return Ok(Self::default()),
+            Err(err) => return Err(err),
+        };
+        let mut ledger = Self::default();
+        for (i, line) in text.lines().enumerate() {
+            if line.starts_with('#') {
+                if line != HEADER {
+                    ledger.comments.push(line.to_string());
+                }
+                continue;
+            }
+            if line.trim().is_empty() {
+                continue;
+            }
+            let malformed = |reason: &str| {
+                InputError::Malformed(path.to_path_buf(), format!("line {}: {}", i + 1, reason))
+            };
+            let mut fields = line.splitn(3, ' ');
+            let day = fields
+                .next()
+                .and_then(|d| d.parse::<u8>().ok())
+                .ok_or_else(|| malformed("expected a day number"))?;
+            let part = fields
+                .next()
+                .and_then(|p| p.parse::<u8>().ok())
+                .ok_or_else(|| malformed("expected a part number"))?;
+            let answer = fields.next().unwrap_or("");
+            ledger.answers.insert((day, part), unescape(answer));
+        }
+        Ok(ledger)
+    }
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), InputError> {
+        let path = path.as_ref();
+        let lines = std::iter::once(HEADER.to_string())
+            .chain(self.comments.iter().cloned())
+            .chain(
+                self.answers
+                    .iter()
+                    .map(|((day, part), answer)| match answer.as_str() {
+                        "" => format!("{:02} {}", day, part),
+                        answer => format!("{:02} {} {}", day, part, escape(answer)),
+                    }),
+            )
+            .map(|line| line + "\n")
+            .collect::<String>();
+        write(path, lines).map_err(|err| InputError::Io(path.to_path_buf(), err))
+    }
+    pub fn get(&self, day: u8, part: u8) -> Option<&str> {
+        self.answers
+            .get(&(day, part))
+            .map(|a| a.as_str())
+            .filter(|a| !a.is_empty())
+    }
+    pub fn check(&self, day: u8, part: u8, answer: &Answer) -> Verdict {
+        match self.get(day, part) {
+            None => Verdict::Unknown,
+            Some(known) if *known == answer.to_string() => Verdict::Pass,
+            Some(known) => Verdict::Fail(known.to_string()),
+        }
+    }
+    pub fn record(&mut self, day: u8, part: u8, answer: &Answer) {
+        self.answers.insert((day, part), answer.to_string());
+    }
+    // Placeholders for both parts of a new day, unless it has answers already.
+    pub fn add_day(&mut self, day: u8) {
+        for part in [1, 2] {
+            self.answers.entry((day, part)).or_default();
+        }
+    }
+}
+
+fn escape(answer: &str) -> String {
+    answer.replace('\\', "\\\\").replace('\n', "\\n")
+}
+
+fn unescape(answer: &str) -> String {
+    let mut text = String::new();
+    let mut chars = answer.chars();
+    while let Some(c) = chars.next() {
+        match (c, chars.clone().next()) {
+            ('\\', Some('n')) => {
+                text.push('\n');
+                chars.next();
+            }
+            ('\\', Some('\\')) => {
+                text.push('\\');
+                chars.next();
+            }
+            (c, _) => text.push(c),
+        }
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn verdicts() {
+        let mut ledger = Ledger::default();
+        ledger.record(1, 1, &Answer::from(24000_u64));
+        assert_eq!(ledger.check(1, 1, &24000_u64.into()), Verdict::Pass);
+        assert_eq!(
+            ledger.check(1, 1, &1_u64.into()),
+            Verdict::Fail("24000".to_string())
+        );
+        assert_eq!(ledger.check(1, 2, &1_u64.into()), Verdict::Unknown);
+        ledger.add_day(1);
+        assert_eq!(ledger.check(1, 1, &24000_u64.into()), Verdict::Pass);
+        assert_eq!(ledger.check(1, 2, &1_u64.into()), Verdict::Unknown);
+        ledger.record(21, 2, &Answer::from(-301_i64));
+        assert_eq!(ledger.get(21, 2), Some("-301"));
+    }
+
+    #[test]
+    fn art_round_trip() {
+        let art = "#..#\n.##.";
+        assert_eq!(unescape(&escape(art)), art);
+    }
+}
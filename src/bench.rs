@@ -0,0 +1,126 @@
+// Benchmarks
+//
+// Times parsing and each part separately. Every iteration parses the input
+// again so the parse timings are as honest as the part timings.
+
+use crate::days::Day;
+use crate::ParseError;
+use std::fmt;
+use std::time::Duration;
+
+#[derive(Debug, Clone, Copy)]
+pub struct BenchOptions {
+    pub warmup: usize,
+    pub iterations: usize,
+}
+impl Default for BenchOptions {
+    fn default() -> Self {
+        Self {
+            warmup: 1,
+            iterations: 5,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stage {
+    Parse,
+    Part(u8),
+}
+impl fmt::Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Stage::Parse => write!(f, "parse"),
+            Stage::Part(part) => write!(f, "part {}", part),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        let mut samples = samples.to_vec();
+        samples.sort();
+        Self {
+            min: samples[0],
+            median: samples[samples.len() / 2],
+            max: samples[samples.len() - 1],
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct BenchReport {
+    pub day: u8,
+    pub title: &'static str,
+    pub stages: Vec<(Stage, Stats)>,
+}
+
+pub fn bench_day(
+    day: &Day,
+    input_text: &str,
+    parts: &[u8],
+    options: BenchOptions,
+) -> Result<BenchReport, ParseError> {
+    for _ in 0..options.warmup {
+        day.run(input_text, parts)?;
+    }
+    let runs = (0..options.iterations.max(1))
+        .map(|_| day.run(input_text, parts))
+        .collect::<Result<Vec<_>, _>>()?;
+    let parse = runs
+        .iter()
+        .map(|run| run.parse_duration)
+        .collect::<Vec<_>>();
+    let stages = std::iter::once((Stage::Parse, Stats::from_samples(&parse)))
+        .chain(parts.iter().enumerate().map(|(i, part)| {
+            let samples = runs
+                .iter()
+                .map(|run| run.parts[i].duration)
+                .collect::<Vec<_>>();
+            (Stage::Part(*part), Stats::from_samples(&samples))
+        }))
+        .collect();
+    Ok(BenchReport {
+        day: day.day,
+        title: day.title,
+        stages,
+    })
+}
+
+// Human friendly duration with a unit that keeps the number short.
+pub fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos() as f64;
+    match nanos {
+        n if n < 1e3 => format!("{:.0}ns", n),
+        n if n < 1e6 => format!("{:.1}µs", n / 1e3),
+        n if n < 1e9 => format!("{:.1}ms", n / 1e6),
+        n => format!("{:.2}s", n / 1e9),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stats() {
+        let samples = [5, 1, 3, 4, 2].map(Duration::from_millis);
+        let stats = Stats::from_samples(&samples);
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_millis(3));
+        assert_eq!(stats.max, Duration::from_millis(5));
+    }
+
+    #[test]
+    fn durations() {
+        assert_eq!(format_duration(Duration::from_nanos(512)), "512ns");
+        assert_eq!(format_duration(Duration::from_micros(1500)), "1.5ms");
+        assert_eq!(format_duration(Duration::from_secs(2)), "2.00s");
+    }
+}
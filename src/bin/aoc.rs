@@ -0,0 +1,505 @@
+// Advent Of Code 2022 runner
+//
+// Usage:
+//   aoc run <days> [--part <1|2>] [--input <file|->] [--record [--overwrite]]
+//           [--format <text|json|csv>] [--jobs <n>] [--timeout <seconds>] [--trace <filter>]
+//   aoc bench <days> [--part <1|2>] [--warmup <n>] [--iterations <n>]
+//   aoc new <day> <slug>
+//   aoc describe <day>
+//   aoc fuzz <days> [--runs <n>] [--seed <n>]
+//   aoc gen <day> [--size <n>] [--seed <n>]
+//   aoc diff <days> [--runs <n>] [--size <n>] [--seed <n>]
+//
+// Where <days> is a single day (`5`), a range (`3..=9` or `3..10`) or `all`.
+// Inputs are read from `data/day_NN_input.txt` (or `$AOC_DATA_DIR`) unless
+// `--input` points at another file or `-` for stdin. A day whose input can't
+// be read or parsed gets FAIL rows with the reason, so does a part that
+// panics, and the others carry on.
+//
+// Every answer is checked against `data/answers.txt` and reported as PASS, FAIL
+// or UNKNOWN. `--record` saves this run's new answers as the known-good ones;
+// answers that FAIL are only replaced with `--overwrite`. The ledger is about
+// the days' own inputs, so answers to an `--input` are always UNKNOWN and
+// can't be recorded.
+// `--format json` or `--format csv` prints one record per part with its day,
+// answer, duration and verification status instead of the usual text.
+//
+// `--jobs` runs every day and part as a job of its own on that many threads,
+// and `--timeout` cancels jobs that take longer: they're TIMEOUT rows, with
+// their progress so far if they have any. A job that panics or can't parse its
+// input is a FAIL row. Either way the others carry on.
+//
+// `--trace` shows what the days are doing on stderr: `--trace debug` for every
+// day, `--trace 12` for everything day 12 has to say, or a list of them like
+// `--trace 12=debug,19=trace`. Levels are info, debug and trace.
+//
+// `bench` times parsing and each part separately and prints min/median/max
+// over the iterations, after a few warmup runs.
+//
+// `new` starts a day from `src/problem_template.rs`: `aoc new 24 blizzard_basin`
+// creates and registers `src/days/day_24_blizzard_basin.rs` along with an
+// example to fill in and placeholder answers. Existing days are left alone.
+//
+// `describe` prints the puzzle text from `puzzles/day_NN.md`.
+// `extract-descriptions` is the one-time migration that moved the puzzle
+// text pasted at the top of each day into those files.
+//
+// `fuzz` feeds each day's parser mangled generated inputs and saves the ones
+// that made it panic under `data/fuzz/`, where `tests/fuzz.rs` replays them.
+//
+// `gen` prints a random input for a day, `--size` (100 by default) being about
+// how many records it has: elves for day 1, the side of day 8's forest, valves
+// for day 16... The same seed and size always give the same input, so
+// `aoc gen 8 --size 2000 | aoc bench 8 --input -` is a repeatable stress test.
+//
+// `diff` solves generated inputs, sizes 1 to `--size`, with both the fast and
+// the reference solution of days that have one, and stops at the first input
+// they disagree on.
+
+use aoc2022::answers::{Ledger, Verdict};
+use aoc2022::bench::{bench_day, format_duration, BenchOptions};
+use aoc2022::days::{find_day, Day, DAYS};
+use aoc2022::fuzz::{self, save_crasher, FuzzOptions};
+use aoc2022::generate::{generate_input, GenOptions};
+use aoc2022::pool::{run_jobs, run_part, Job, JobError, PoolOptions};
+use aoc2022::puzzles::{description_path, extract_all, load_description, puzzles_dir};
+use aoc2022::reference::{differential, DiffOptions};
+use aoc2022::report::{Format, Record, Reporter};
+use aoc2022::scaffold::NewDay;
+use aoc2022::trace::{self, Filter};
+use aoc2022::{Answer, Cancel, InputError, InputSource, PartResult};
+use std::error::Error;
+use std::path::Path;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+const USAGE: &str = "Usage:
+  aoc run <day|all|first..=last> [--part <1|2>] [--input <file|->] [--record [--overwrite]]
+      [--format <text|json|csv>] [--jobs <n>] [--timeout <seconds>] [--trace <filter>]
+  aoc bench <day|all|first..=last> [--part <1|2>] [--warmup <n>] [--iterations <n>]
+  aoc new <day> <slug>
+  aoc describe <day>
+  aoc fuzz <day|all|first..=last> [--runs <n>] [--seed <n>]
+  aoc gen <day> [--size <n>] [--seed <n>]
+  aoc diff <day|all|first..=last> [--runs <n>] [--size <n>] [--seed <n>]";
+
+struct RunOptions {
+    parts: Vec<u8>,
+    input: Option<InputSource>,
+    record: bool,
+    overwrite: bool,
+    format: Format,
+    bench: BenchOptions,
+    pool: Option<PoolOptions>,
+    trace: Filter,
+    fuzz: FuzzOptions,
+    gen: GenOptions,
+    diff: DiffOptions,
+}
+
+fn parse_days(days: &str) -> Result<Vec<&'static Day>, Box<dyn Error>> {
+    if days == "all" {
+        return Ok(DAYS.iter().collect());
+    }
+    let (first, last) = if let Some((first, last)) = days.split_once("..=") {
+        (first.parse::<u8>()?, last.parse::<u8>()?)
+    } else if let Some((first, last)) = days.split_once("..") {
+        let last = last.parse::<u8>()?.checked_sub(1);
+        (
+            first.parse::<u8>()?,
+            last.ok_or_else(|| format!("No days in {days}"))?,
+        )
+    } else {
+        let day = days.parse::<u8>()?;
+        (day, day)
+    };
+    if first > last {
+        return Err(format!("No days in {days}").into());
+    }
+    (first..=last)
+        .map(|day| find_day(day).ok_or_else(|| format!("Day {day} not found").into()))
+        .collect()
+}
+
+fn parse_parts(part: Option<&str>) -> Result<Vec<u8>, Box<dyn Error>> {
+    match part {
+        None => Ok(vec![1, 2]),
+        Some("1") => Ok(vec![1]),
+        Some("2") => Ok(vec![2]),
+        Some(p) => Err(format!("Part {p} not found").into()),
+    }
+}
+
+fn parse_options(args: &[&str]) -> Result<RunOptions, Box<dyn Error>> {
+    let mut options = RunOptions {
+        parts: parse_parts(None)?,
+        input: None,
+        record: false,
+        overwrite: false,
+        format: Format::Text,
+        bench: BenchOptions::default(),
+        pool: None,
+        trace: Filter::off(),
+        fuzz: FuzzOptions::default(),
+        gen: GenOptions::default(),
+        diff: DiffOptions::default(),
+    };
+    let mut args = args.iter();
+    while let Some(flag) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| format!("Missing value for {flag}"))
+        };
+        match *flag {
+            "--part" => options.parts = parse_parts(Some(value()?))?,
+            "--input" => options.input = Some(InputSource::from_arg(value()?)),
+            "--record" => options.record = true,
+            "--overwrite" => options.overwrite = true,
+            "--format" => options.format = value()?.parse()?,
+            "--jobs" => {
+                options.pool.get_or_insert_with(PoolOptions::default).jobs = value()?.parse()?
+            }
+            "--timeout" => {
+                let timeout = Duration::from_secs_f64(value()?.parse()?);
+                options
+                    .pool
+                    .get_or_insert_with(PoolOptions::default)
+                    .timeout = Some(timeout);
+            }
+            "--trace" => options.trace = value()?.parse()?,
+            "--warmup" => options.bench.warmup = value()?.parse()?,
+            "--iterations" => options.bench.iterations = value()?.parse()?,
+            "--runs" => {
+                let runs = value()?.parse()?;
+                options.fuzz.runs = runs;
+                options.diff.runs = runs;
+            }
+            "--seed" => {
+                let seed = value()?.parse()?;
+                options.fuzz.seed = seed;
+                options.gen.seed = seed;
+                options.diff.seed = seed;
+            }
+            "--size" => {
+                let size = value()?.parse()?;
+                options.gen.size = size;
+                options.diff.size = size;
+            }
+            other => return Err(format!("Unknown option {other}\n{USAGE}").into()),
+        }
+    }
+    Ok(options)
+}
+
+fn load_day_input(day: &Day, options: &RunOptions) -> Result<String, Box<dyn Error>> {
+    let source = options.input.clone().unwrap_or(InputSource::Day(day.day));
+    Ok(source.load()?)
+}
+
+// What a part came to, or why it came to nothing along with its verdict.
+type Outcome = Result<PartResult, (String, Verdict, Duration)>;
+
+fn run(days: &[&'static Day], options: &RunOptions) -> Result<(), Box<dyn Error>> {
+    if options.input.is_some() && days.len() != 1 {
+        return Err("--input can only be used when running a single day".into());
+    }
+    if options.input.is_some() && options.record {
+        return Err("--record only saves answers to the days' own inputs, not --input".into());
+    }
+    if options.overwrite && !options.record {
+        return Err("--overwrite only makes sense with --record".into());
+    }
+    trace::set_filter(options.trace.clone());
+    let mut ledger = Ledger::load(Ledger::path())?;
+    let mut reporter = Reporter::new(options.format);
+    let (mut failed, mut errored, mut timed_out) = (0, 0, 0);
+    if let Some(begin) = reporter.begin() {
+        println!("{begin}");
+    }
+    let mut report = |day: u8, part: u8, result: Outcome| {
+        let (answer, duration, verdict) = match result {
+            Ok(PartResult {
+                answer: Ok(answer),
+                duration,
+                ..
+            }) => {
+                // The ledger knows nothing about other inputs.
+                let verdict = match options.input {
+                    Some(_) => Verdict::Unknown,
+                    None => ledger.check(day, part, &answer),
+                };
+                let replaces = matches!(verdict, Verdict::Fail(_));
+                if options.record && (!replaces || options.overwrite) {
+                    ledger.record(day, part, &answer);
+                }
+                (answer, duration, verdict)
+            }
+            // Stopped with whatever progress it had, which isn't an answer.
+            Ok(PartResult {
+                answer: Err(cancelled),
+                duration,
+                ..
+            }) => (
+                Answer::Text(cancelled.to_string()),
+                duration,
+                Verdict::Timeout,
+            ),
+            Err((reason, verdict, duration)) => (Answer::Text(reason), duration, verdict),
+        };
+        match verdict {
+            Verdict::Fail(_) => failed += 1,
+            Verdict::Error => errored += 1,
+            Verdict::Timeout => timed_out += 1,
+            _ => {}
+        }
+        let record = Record {
+            day,
+            part,
+            answer,
+            duration,
+            verdict,
+        };
+        println!("{}", reporter.record(&record));
+    };
+    // Inputs that can't be loaded or parsed are FAIL rows for every part.
+    let failed_parts = |day: u8, err: &dyn Error| {
+        let reason = err.to_string();
+        options.parts.iter().map(move |part| {
+            (
+                day,
+                *part,
+                Err((reason.clone(), Verdict::Error, Duration::ZERO)),
+            )
+        })
+    };
+    if let Some(pool) = options.pool {
+        let (mut jobs, mut unloaded) = (vec![], vec![]);
+        for day in days {
+            let input: Arc<str> = match load_day_input(day, options) {
+                Ok(input) => Arc::from(input),
+                Err(err) => {
+                    unloaded.extend(failed_parts(day.day, err.as_ref()));
+                    continue;
+                }
+            };
+            for part in &options.parts {
+                jobs.push(Job {
+                    day,
+                    part: *part,
+                    input: Arc::clone(&input),
+                });
+            }
+        }
+        // Panics are reported as FAIL rows. Days without an input get theirs
+        // in between, so that every row stays in order.
+        let mut unloaded = unloaded.into_iter().peekable();
+        let hook = std::panic::take_hook();
+        std::panic::set_hook(Box::new(|_| {}));
+        run_jobs(jobs, pool, |job| {
+            while let Some((day, part, result)) = unloaded.next_if(|row| row.0 < job.day) {
+                report(day, part, result);
+            }
+            let outcome = job.outcome.map_err(|err| {
+                let verdict = match err {
+                    JobError::Timeout(_) => Verdict::Timeout,
+                    _ => Verdict::Error,
+                };
+                (err.to_string(), verdict, job.duration)
+            });
+            report(job.day, job.part, outcome)
+        });
+        std::panic::set_hook(hook);
+        for (day, part, result) in unloaded {
+            report(day, part, result);
+        }
+    } else {
+        // Each part on its own, so that one panicking keeps the other.
+        let hook = std::panic::take_hook();
+        std::panic::set_hook(Box::new(|_| {}));
+        for day in days {
+            let input_text = match load_day_input(day, options) {
+                Ok(input_text) => input_text,
+                Err(err) => {
+                    for (day, part, result) in failed_parts(day.day, err.as_ref()) {
+                        report(day, part, result);
+                    }
+                    continue;
+                }
+            };
+            for part in &options.parts {
+                let start = Instant::now();
+                let outcome = run_part(day, &input_text, *part, &Cancel::default())
+                    .map_err(|err| (err.to_string(), Verdict::Error, start.elapsed()));
+                report(day.day, *part, outcome);
+            }
+        }
+        std::panic::set_hook(hook);
+    }
+    if let Some(end) = reporter.end() {
+        println!("{end}");
+    }
+    if options.record {
+        ledger.save(Ledger::path())?;
+    }
+    let problems = [
+        (failed, "answers failed verification"),
+        (errored, "parts failed without an answer"),
+        (timed_out, "parts timed out"),
+    ]
+    .iter()
+    .filter(|(count, _)| *count > 0)
+    .map(|(count, problem)| format!("{count} {problem}"))
+    .collect::<Vec<String>>();
+    if !problems.is_empty() {
+        return Err(problems.join(", ").into());
+    }
+    Ok(())
+}
+
+fn bench(days: &[&Day], options: &RunOptions) -> Result<(), Box<dyn Error>> {
+    if options.input.is_some() && days.len() != 1 {
+        return Err("--input can only be used when running a single day".into());
+    }
+    println!(
+        "{:<4} {:<28} {:<7} {:>10} {:>10} {:>10}",
+        "Day", "Title", "Stage", "Min", "Median", "Max"
+    );
+    for day in days {
+        let input_text = load_day_input(day, options)?;
+        let report = bench_day(day, &input_text, &options.parts, options.bench)?;
+        for (stage, stats) in report.stages {
+            println!(
+                "{:<4} {:<28} {:<7} {:>10} {:>10} {:>10}",
+                format!("{:02}", report.day),
+                report.title,
+                stage.to_string(),
+                format_duration(stats.min),
+                format_duration(stats.median),
+                format_duration(stats.max)
+            );
+        }
+    }
+    Ok(())
+}
+
+fn fuzz(days: &[&Day], options: &RunOptions) -> Result<(), Box<dyn Error>> {
+    // The panics are the point, keep their messages for the summary.
+    let hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(|_| {}));
+    let crashes = days
+        .iter()
+        .map(|day| (day, fuzz::fuzz(day, options.fuzz)))
+        .collect::<Vec<_>>();
+    std::panic::set_hook(hook);
+    let mut crashed = 0;
+    for (day, crashes) in crashes {
+        println!(
+            "{:02} {:<28} {} runs, {} panics",
+            day.day,
+            day.title,
+            options.fuzz.runs,
+            crashes.len()
+        );
+        for crash in &crashes {
+            let path = save_crasher(crash)?;
+            println!("   {}: {}", path.display(), crash.message);
+        }
+        crashed += crashes.len();
+    }
+    if crashed > 0 {
+        return Err(format!("{crashed} inputs made a parser panic").into());
+    }
+    Ok(())
+}
+
+fn gen(day: &str, options: &RunOptions) -> Result<(), Box<dyn Error>> {
+    let day = day.parse::<u8>()?;
+    let day = find_day(day).ok_or_else(|| format!("Day {day} not found"))?;
+    println!("{}", generate_input(day, options.gen));
+    Ok(())
+}
+
+fn diff(days: &[&Day], options: &RunOptions) -> Result<(), Box<dyn Error>> {
+    let days = days
+        .iter()
+        .filter(|day| day.compare.is_some())
+        .collect::<Vec<_>>();
+    if days.is_empty() {
+        return Err("None of these days has a reference solution".into());
+    }
+    // Panics are reported as disagreements.
+    let hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(|_| {}));
+    let disagreements = days
+        .iter()
+        .map(|day| (day, differential(day, options.diff)))
+        .collect::<Vec<_>>();
+    std::panic::set_hook(hook);
+    let mut failed = 0;
+    for (day, disagreement) in disagreements {
+        match disagreement {
+            None => println!(
+                "{:02} {:<28} {} runs, agreed",
+                day.day, day.title, options.diff.runs
+            ),
+            Some(disagreement) => {
+                println!("{:02} {:<28} disagreed", day.day, day.title);
+                println!("{}", disagreement);
+                failed += 1;
+            }
+        }
+    }
+    if failed > 0 {
+        return Err(format!("{failed} days disagreed with their reference").into());
+    }
+    Ok(())
+}
+
+fn new(day: &str, slug: &str) -> Result<(), Box<dyn Error>> {
+    let new_day = NewDay::new(day.parse()?, slug)?;
+    let src_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("src");
+    for path in new_day.create(&src_dir, &puzzles_dir())? {
+        println!("Wrote {}", path.display());
+    }
+    Ok(())
+}
+
+fn describe(day: &str) -> Result<(), Box<dyn Error>> {
+    let day = day.parse::<u8>()?;
+    match load_description(day) {
+        Ok(description) => {
+            println!("{description}");
+            Ok(())
+        }
+        Err(InputError::NotFound(_)) => Err(format!(
+            "No description for day {day}, expected {}",
+            description_path(day).display()
+        )
+        .into()),
+        Err(err) => Err(err.into()),
+    }
+}
+
+fn extract_descriptions() -> Result<(), Box<dyn Error>> {
+    let src_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("src");
+    for path in extract_all(&src_dir)? {
+        println!("Wrote {}", path.display());
+    }
+    Ok(())
+}
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let args = std::env::args().skip(1).collect::<Vec<String>>();
+    let args = args.iter().map(|a| a.as_str()).collect::<Vec<&str>>();
+    match args.as_slice() {
+        ["run", days, options @ ..] => run(&parse_days(days)?, &parse_options(options)?),
+        ["bench", days, options @ ..] => bench(&parse_days(days)?, &parse_options(options)?),
+        ["fuzz", days, options @ ..] => fuzz(&parse_days(days)?, &parse_options(options)?),
+        ["gen", day, options @ ..] => gen(day, &parse_options(options)?),
+        ["diff", days, options @ ..] => diff(&parse_days(days)?, &parse_options(options)?),
+        ["new", day, slug] => new(day, slug),
+        ["describe", day] => describe(day),
+        ["extract-descriptions"] => extract_descriptions(),
+        _ => Err(USAGE.into()),
+    }
+}
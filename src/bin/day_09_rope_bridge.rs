@@ -1,908 +0,0 @@
-// Advent Of Code
-// https://adventofcode.com/2022/day/9
-//
-// --- Day 9: Rope Bridge ---
-// This rope bridge creaks as you walk along it. You aren't sure how old it is, or whether it can even support your weight.
-//
-// It seems to support the Elves just fine, though. The bridge spans a gorge which was carved out by the massive river far below you.
-//
-// You step carefully; as you do, the ropes stretch and twist. You decide to distract yourself by modeling rope physics; maybe you can even figure out where not to step.
-//
-// Consider a rope with a knot at each end; these knots mark the head and the tail of the rope. If the head moves far enough away from the tail, the tail is pulled toward the head.
-//
-// Due to nebulous reasoning involving Planck lengths, you should be able to model the positions of the knots on a two-dimensional grid. Then, by following a hypothetical series of motions (your puzzle input) for the head, you can determine how the tail will move.
-//
-// Due to the aforementioned Planck lengths, the rope must be quite short; in fact, the head (H) and tail (T) must always be touching (diagonally adjacent and even overlapping both count as touching):
-//
-// ....
-// .TH.
-// ....
-//
-// ....
-// .H..
-// ..T.
-// ....
-//
-// ...
-// .H. (H covers T)
-// ...
-// If the head is ever two steps directly up, down, left, or right from the tail, the tail must also move one step in that direction so it remains close enough:
-//
-// .....    .....    .....
-// .TH.. -> .T.H. -> ..TH.
-// .....    .....    .....
-//
-// ...    ...    ...
-// .T.    .T.    ...
-// .H. -> ... -> .T.
-// ...    .H.    .H.
-// ...    ...    ...
-// Otherwise, if the head and tail aren't touching and aren't in the same row or column, the tail always moves one step diagonally to keep up:
-//
-// .....    .....    .....
-// .....    ..H..    ..H..
-// ..H.. -> ..... -> ..T..
-// .T...    .T...    .....
-// .....    .....    .....
-//
-// .....    .....    .....
-// .....    .....    .....
-// ..H.. -> ...H. -> ..TH.
-// .T...    .T...    .....
-// .....    .....    .....
-// You just need to work out where the tail goes as the head follows a series of motions. Assume the head and the tail both start at the same position, overlapping.
-//
-// For example:
-//
-// R 4
-// U 4
-// L 3
-// D 1
-// R 4
-// D 1
-// L 5
-// R 2
-// This series of motions moves the head right four steps, then up four steps, then left three steps, then down one step, and so on. After each step, you'll need to update the position of the tail if the step means the head is no longer adjacent to the tail. Visually, these motions occur as follows (s marks the starting position as a reference point):
-//
-// == Initial State ==
-//
-// ......
-// ......
-// ......
-// ......
-// H.....  (H covers T, s)
-//
-// == R 4 ==
-//
-// ......
-// ......
-// ......
-// ......
-// TH....  (T covers s)
-//
-// ......
-// ......
-// ......
-// ......
-// sTH...
-//
-// ......
-// ......
-// ......
-// ......
-// s.TH..
-//
-// ......
-// ......
-// ......
-// ......
-// s..TH.
-//
-// == U 4 ==
-//
-// ......
-// ......
-// ......
-// ....H.
-// s..T..
-//
-// ......
-// ......
-// ....H.
-// ....T.
-// s.....
-//
-// ......
-// ....H.
-// ....T.
-// ......
-// s.....
-//
-// ....H.
-// ....T.
-// ......
-// ......
-// s.....
-//
-// == L 3 ==
-//
-// ...H..
-// ....T.
-// ......
-// ......
-// s.....
-//
-// ..HT..
-// ......
-// ......
-// ......
-// s.....
-//
-// .HT...
-// ......
-// ......
-// ......
-// s.....
-//
-// == D 1 ==
-//
-// ..T...
-// .H....
-// ......
-// ......
-// s.....
-//
-// == R 4 ==
-//
-// ..T...
-// ..H...
-// ......
-// ......
-// s.....
-//
-// ..T...
-// ...H..
-// ......
-// ......
-// s.....
-//
-// ......
-// ...TH.
-// ......
-// ......
-// s.....
-//
-// ......
-// ....TH
-// ......
-// ......
-// s.....
-//
-// == D 1 ==
-//
-// ......
-// ....T.
-// .....H
-// ......
-// s.....
-//
-// == L 5 ==
-//
-// ......
-// ....T.
-// ....H.
-// ......
-// s.....
-//
-// ......
-// ....T.
-// ...H..
-// ......
-// s.....
-//
-// ......
-// ......
-// ..HT..
-// ......
-// s.....
-//
-// ......
-// ......
-// .HT...
-// ......
-// s.....
-//
-// ......
-// ......
-// HT....
-// ......
-// s.....
-//
-// == R 2 ==
-//
-// ......
-// ......
-// .H....  (H covers T)
-// ......
-// s.....
-//
-// ......
-// ......
-// .TH...
-// ......
-// s.....
-// After simulating the rope, you can count up all of the positions the tail visited at least once. In this diagram, s again marks the starting position (which the tail also visited) and # marks other positions the tail visited:
-//
-// ..##..
-// ...##.
-// .####.
-// ....#.
-// s###..
-// So, there are 13 positions the tail visited at least once.
-//
-// Simulate your complete hypothetical series of motions. How many positions does the tail of the rope visit at least once?
-//
-// --- Part Two ---
-// A rope snaps! Suddenly, the river is getting a lot closer than you remember. The bridge is still there, but some of the ropes that broke are now whipping toward you as you fall through the air!
-//
-// The ropes are moving too quickly to grab; you only have a few seconds to choose how to arch your body to avoid being hit. Fortunately, your simulation can be extended to support longer ropes.
-//
-// Rather than two knots, you now must simulate a rope consisting of ten knots. One knot is still the head of the rope and moves according to the series of motions. Each knot further down the rope follows the knot in front of it using the same rules as before.
-//
-// Using the same series of motions as the above example, but with the knots marked H, 1, 2, ..., 9, the motions now occur as follows:
-//
-// == Initial State ==
-//
-// ......
-// ......
-// ......
-// ......
-// H.....  (H covers 1, 2, 3, 4, 5, 6, 7, 8, 9, s)
-//
-// == R 4 ==
-//
-// ......
-// ......
-// ......
-// ......
-// 1H....  (1 covers 2, 3, 4, 5, 6, 7, 8, 9, s)
-//
-// ......
-// ......
-// ......
-// ......
-// 21H...  (2 covers 3, 4, 5, 6, 7, 8, 9, s)
-//
-// ......
-// ......
-// ......
-// ......
-// 321H..  (3 covers 4, 5, 6, 7, 8, 9, s)
-//
-// ......
-// ......
-// ......
-// ......
-// 4321H.  (4 covers 5, 6, 7, 8, 9, s)
-//
-// == U 4 ==
-//
-// ......
-// ......
-// ......
-// ....H.
-// 4321..  (4 covers 5, 6, 7, 8, 9, s)
-//
-// ......
-// ......
-// ....H.
-// .4321.
-// 5.....  (5 covers 6, 7, 8, 9, s)
-//
-// ......
-// ....H.
-// ....1.
-// .432..
-// 5.....  (5 covers 6, 7, 8, 9, s)
-//
-// ....H.
-// ....1.
-// ..432.
-// .5....
-// 6.....  (6 covers 7, 8, 9, s)
-//
-// == L 3 ==
-//
-// ...H..
-// ....1.
-// ..432.
-// .5....
-// 6.....  (6 covers 7, 8, 9, s)
-//
-// ..H1..
-// ...2..
-// ..43..
-// .5....
-// 6.....  (6 covers 7, 8, 9, s)
-//
-// .H1...
-// ...2..
-// ..43..
-// .5....
-// 6.....  (6 covers 7, 8, 9, s)
-//
-// == D 1 ==
-//
-// ..1...
-// .H.2..
-// ..43..
-// .5....
-// 6.....  (6 covers 7, 8, 9, s)
-//
-// == R 4 ==
-//
-// ..1...
-// ..H2..
-// ..43..
-// .5....
-// 6.....  (6 covers 7, 8, 9, s)
-//
-// ..1...
-// ...H..  (H covers 2)
-// ..43..
-// .5....
-// 6.....  (6 covers 7, 8, 9, s)
-//
-// ......
-// ...1H.  (1 covers 2)
-// ..43..
-// .5....
-// 6.....  (6 covers 7, 8, 9, s)
-//
-// ......
-// ...21H
-// ..43..
-// .5....
-// 6.....  (6 covers 7, 8, 9, s)
-//
-// == D 1 ==
-//
-// ......
-// ...21.
-// ..43.H
-// .5....
-// 6.....  (6 covers 7, 8, 9, s)
-//
-// == L 5 ==
-//
-// ......
-// ...21.
-// ..43H.
-// .5....
-// 6.....  (6 covers 7, 8, 9, s)
-//
-// ......
-// ...21.
-// ..4H..  (H covers 3)
-// .5....
-// 6.....  (6 covers 7, 8, 9, s)
-//
-// ......
-// ...2..
-// ..H1..  (H covers 4; 1 covers 3)
-// .5....
-// 6.....  (6 covers 7, 8, 9, s)
-//
-// ......
-// ...2..
-// .H13..  (1 covers 4)
-// .5....
-// 6.....  (6 covers 7, 8, 9, s)
-//
-// ......
-// ......
-// H123..  (2 covers 4)
-// .5....
-// 6.....  (6 covers 7, 8, 9, s)
-//
-// == R 2 ==
-//
-// ......
-// ......
-// .H23..  (H covers 1; 2 covers 4)
-// .5....
-// 6.....  (6 covers 7, 8, 9, s)
-//
-// ......
-// ......
-// .1H3..  (H covers 2, 4)
-// .5....
-// 6.....  (6 covers 7, 8, 9, s)
-// Now, you need to keep track of the positions the new tail, 9, visits. In this example, the tail never moves, and so it only visits 1 position. However, be careful: more types of motion are possible than before, so you might want to visually compare your simulated rope to the one above.
-//
-// Here's a larger example:
-//
-// R 5
-// U 8
-// L 8
-// D 3
-// R 17
-// D 10
-// L 25
-// U 20
-// These motions occur as follows (individual steps are not shown):
-//
-// == Initial State ==
-//
-// ..........................
-// ..........................
-// ..........................
-// ..........................
-// ..........................
-// ..........................
-// ..........................
-// ..........................
-// ..........................
-// ..........................
-// ..........................
-// ..........................
-// ..........................
-// ..........................
-// ..........................
-// ...........H..............  (H covers 1, 2, 3, 4, 5, 6, 7, 8, 9, s)
-// ..........................
-// ..........................
-// ..........................
-// ..........................
-// ..........................
-//
-// == R 5 ==
-//
-// ..........................
-// ..........................
-// ..........................
-// ..........................
-// ..........................
-// ..........................
-// ..........................
-// ..........................
-// ..........................
-// ..........................
-// ..........................
-// ..........................
-// ..........................
-// ..........................
-// ..........................
-// ...........54321H.........  (5 covers 6, 7, 8, 9, s)
-// ..........................
-// ..........................
-// ..........................
-// ..........................
-// ..........................
-//
-// == U 8 ==
-//
-// ..........................
-// ..........................
-// ..........................
-// ..........................
-// ..........................
-// ..........................
-// ..........................
-// ................H.........
-// ................1.........
-// ................2.........
-// ................3.........
-// ...............54.........
-// ..............6...........
-// .............7............
-// ............8.............
-// ...........9..............  (9 covers s)
-// ..........................
-// ..........................
-// ..........................
-// ..........................
-// ..........................
-//
-// == L 8 ==
-//
-// ..........................
-// ..........................
-// ..........................
-// ..........................
-// ..........................
-// ..........................
-// ..........................
-// ........H1234.............
-// ............5.............
-// ............6.............
-// ............7.............
-// ............8.............
-// ............9.............
-// ..........................
-// ..........................
-// ...........s..............
-// ..........................
-// ..........................
-// ..........................
-// ..........................
-// ..........................
-//
-// == D 3 ==
-//
-// ..........................
-// ..........................
-// ..........................
-// ..........................
-// ..........................
-// ..........................
-// ..........................
-// ..........................
-// .........2345.............
-// ........1...6.............
-// ........H...7.............
-// ............8.............
-// ............9.............
-// ..........................
-// ..........................
-// ...........s..............
-// ..........................
-// ..........................
-// ..........................
-// ..........................
-// ..........................
-//
-// == R 17 ==
-//
-// ..........................
-// ..........................
-// ..........................
-// ..........................
-// ..........................
-// ..........................
-// ..........................
-// ..........................
-// ..........................
-// ..........................
-// ................987654321H
-// ..........................
-// ..........................
-// ..........................
-// ..........................
-// ...........s..............
-// ..........................
-// ..........................
-// ..........................
-// ..........................
-// ..........................
-//
-// == D 10 ==
-//
-// ..........................
-// ..........................
-// ..........................
-// ..........................
-// ..........................
-// ..........................
-// ..........................
-// ..........................
-// ..........................
-// ..........................
-// ..........................
-// ..........................
-// ..........................
-// ..........................
-// ..........................
-// ...........s.........98765
-// .........................4
-// .........................3
-// .........................2
-// .........................1
-// .........................H
-//
-// == L 25 ==
-//
-// ..........................
-// ..........................
-// ..........................
-// ..........................
-// ..........................
-// ..........................
-// ..........................
-// ..........................
-// ..........................
-// ..........................
-// ..........................
-// ..........................
-// ..........................
-// ..........................
-// ..........................
-// ...........s..............
-// ..........................
-// ..........................
-// ..........................
-// ..........................
-// H123456789................
-//
-// == U 20 ==
-//
-// H.........................
-// 1.........................
-// 2.........................
-// 3.........................
-// 4.........................
-// 5.........................
-// 6.........................
-// 7.........................
-// 8.........................
-// 9.........................
-// ..........................
-// ..........................
-// ..........................
-// ..........................
-// ..........................
-// ...........s..............
-// ..........................
-// ..........................
-// ..........................
-// ..........................
-// ..........................
-//
-// Now, the tail (9) visits 36 positions (including s) at least once:
-//
-// ..........................
-// ..........................
-// ..........................
-// ..........................
-// ..........................
-// ..........................
-// ..........................
-// ..........................
-// ..........................
-// #.........................
-// #.............###.........
-// #............#...#........
-// .#..........#.....#.......
-// ..#..........#.....#......
-// ...#........#.......#.....
-// ....#......s.........#....
-// .....#..............#.....
-// ......#............#......
-// .......#..........#.......
-// ........#........#........
-// .........########.........
-// Simulate your complete series of motions on a larger rope with ten knots. How many positions does the tail of the rope visit at least once?
-
-use aoc2022::load_input;
-use std::error::Error;
-
-#[derive(Debug, Copy, Clone)]
-#[repr(u8)]
-enum Direction {
-    UP = b'U',
-    LEFT = b'L',
-    DOWN = b'D',
-    RIGHT = b'R',
-}
-
-struct Move {
-    direction: Direction,
-    steps: i32,
-}
-
-#[derive(Debug, Copy, Clone)]
-struct Pos {
-    x: i32,
-    y: i32,
-}
-
-fn parse_move(move_str: &str) -> Move {
-    let (direction, steps) = move_str.split_once(" ").unwrap();
-    Move {
-        direction: match direction.chars().next().unwrap() {
-            'U' => Direction::UP,
-            'L' => Direction::LEFT,
-            'D' => Direction::DOWN,
-            'R' => Direction::RIGHT,
-            d => panic!("Direction {d} not found"),
-        },
-        steps: steps.parse().unwrap(),
-    }
-}
-
-fn parse_input(input_text: &str) -> Vec<Move> {
-    input_text.lines().map(parse_move).collect::<Vec<_>>()
-}
-
-fn move_rope(moves: Vec<Move>, knots: u32) -> Vec<Vec<Pos>> {
-    let mut rope = vec![vec![Pos { x: 0, y: 0 }]; knots.try_into().unwrap()];
-    let mut head = rope[0][0];
-
-    moves.iter().for_each(|mv| {
-        (0..mv.steps).for_each(|_| {
-            head = move_head(&head, mv.direction);
-            rope[0].push(head);
-            (1..knots as usize).for_each(|k| {
-                let prev_knot = *rope[k - 1].last().unwrap();
-                let mut cur_knot = *rope[k].last().unwrap();
-                cur_knot = follow_tail(&cur_knot, &prev_knot);
-                rope[k].push(cur_knot);
-            })
-        })
-    });
-    rope
-}
-
-fn move_head(head: &Pos, direction: Direction) -> Pos {
-    match direction {
-        Direction::UP => Pos {
-            x: head.x,
-            y: head.y + 1,
-        },
-        Direction::LEFT => Pos {
-            x: head.x - 1,
-            y: head.y,
-        },
-        Direction::DOWN => Pos {
-            x: head.x,
-            y: head.y - 1,
-        },
-        Direction::RIGHT => Pos {
-            x: head.x + 1,
-            y: head.y,
-        },
-    }
-}
-
-fn follow_tail(tail: &Pos, head: &Pos) -> Pos {
-    let (dx, dy) = (tail.x - head.x, tail.y - head.y);
-    match (dx.abs(), dy.abs()) {
-        (x, y) if x <= 1 && y <= 1 => *tail,
-        (0, _) => Pos {
-            x: tail.x,
-            y: tail.y - 1 * dy.signum(),
-        },
-        (_, 0) => Pos {
-            x: tail.x - 1 * dx.signum(),
-            y: tail.y,
-        },
-        (_, _) => Pos {
-            x: tail.x - 1 * dx.signum(),
-            y: tail.y - 1 * dy.signum(),
-        },
-    }
-}
-
-fn board_size(positions: &Vec<Pos>) -> (i32, i32, i32, i32, i32, i32) {
-    let (x0, x1) = (
-        positions.iter().map(|p| p.x).min().unwrap(),
-        positions.iter().map(|p| p.x).max().unwrap(),
-    );
-    let (y0, y1) = (
-        positions.iter().map(|p| p.y).min().unwrap(),
-        positions.iter().map(|p| p.y).max().unwrap(),
-    );
-    let (h, w) = (y1 - y0 + 1, x1 - x0 + 1);
-    (x0, x1, y0, y1, w, h)
-}
-
-fn pos_frequency(positions: &Vec<Pos>) -> Vec<Vec<u64>> {
-    let (x0, _, y0, _, w, h) = board_size(&positions);
-    let mut freq = vec![vec![0; w.try_into().unwrap()]; h.try_into().unwrap()];
-    positions.iter().for_each(|p| {
-        freq[(p.y - y0) as usize][(p.x - x0) as usize] += 1;
-    });
-    freq
-}
-
-fn print_rope(rope: &Vec<Vec<Pos>>) {
-    let (x0, _x1, y0, _y1, w, h) = board_size(&rope[0]);
-    let board = vec![vec!['.'; w.try_into().unwrap()]; h.try_into().unwrap()];
-    let knots = rope.len();
-    let moves = rope[0].len();
-    let mut icons = (b'0'..=b'9').map(char::from).collect::<Vec<_>>();
-    icons[0] = 'H';
-    icons[knots - 1] = 'T';
-    (0..moves).for_each(|m| {
-        println!(
-            "{}",
-            board
-                .iter()
-                .enumerate()
-                .map(|(i, row)| {
-                    let mut row = row.clone();
-                    (0..knots).rev().for_each(|k| {
-                        let mv = rope[k][m];
-                        let y: usize = (mv.y - y0).try_into().unwrap();
-                        if i == y {
-                            row[(mv.x - x0) as usize] = icons[k];
-                        }
-                    });
-                    row.iter().collect::<String>() + "\n"
-                })
-                .rev()
-                .collect::<String>()
-        );
-    })
-}
-
-fn solve(input_text: String, knots: u32) -> u64 {
-    let moves = parse_input(&input_text);
-    let rope = move_rope(moves, knots);
-    let t_freq = pos_frequency(rope.last().unwrap());
-    // print_rope(&rope);
-    const ONCE: u64 = 1;
-    let t_vis_once = t_freq
-        .into_iter()
-        .fold(0 as u64, |acc, row| {
-            acc + row.iter().filter(|c| c >= &&ONCE).count() as u64
-        })
-        .try_into()
-        .unwrap();
-    t_vis_once
-}
-
-fn solve_pt1(input_text: String) -> u64 {
-    const KNOTS: u32 = 2;
-    solve(input_text, KNOTS)
-}
-
-fn solve_pt2(input_text: String) -> u64 {
-    const KNOTS: u32 = 10;
-    solve(input_text, KNOTS)
-}
-
-fn main() -> Result<(), Box<dyn Error>> {
-    const FILENAME: &str = "data/day_09_input.txt";
-    let input_text = load_input(FILENAME);
-
-    print!("Part one: {:#?}\n", solve_pt1(input_text.clone()));
-    // Correct: 6087
-
-    print!("Part two: {:#?}\n", solve_pt2(input_text.clone()));
-    // Correct: 2493
-
-    Ok(())
-}
-
-// Example tests
-#[cfg(test)]
-mod example {
-    use super::*;
-
-    const TEST_DATA: &str = "R 4
-U 4
-L 3
-D 1
-R 4
-D 1
-L 5
-R 2";
-    const ANS_PT1: u64 = 13;
-    const ANS_PT2_0: u64 = 1;
-    const TEST_DATA_PT2_1: &str = "R 5
-U 8
-L 8
-D 3
-R 17
-D 10
-L 25
-U 20";
-
-    const ANS_PT2_1: u64 = 36;
-
-    #[test]
-    fn test_pt1() {
-        assert_eq!(solve_pt1(TEST_DATA.to_string()), ANS_PT1);
-    }
-
-    #[test]
-    fn test_pt2_0() {
-        assert_eq!(solve_pt2(TEST_DATA.to_string()), ANS_PT2_0);
-    }
-
-    #[test]
-    fn test_pt2_1() {
-        assert_eq!(solve_pt2(TEST_DATA_PT2_1.to_string()), ANS_PT2_1);
-    }
-}
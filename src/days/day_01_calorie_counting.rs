@@ -0,0 +1,70 @@
+// Advent Of Code
+// https://adventofcode.com/2022/day/1
+//
+// --- Day 1: Calorie Counting ---
+// Puzzle description: puzzles/day_01.md
+
+use crate::days::Day;
+use crate::generate::{Generate, Rng};
+use crate::parse::{self, ParseError};
+use crate::{Answer, Solution};
+
+fn parse_input(input_text: &str) -> Result<Vec<usize>, ParseError> {
+    let mut calories = parse::blocks(input_text)
+        .iter()
+        .map(|elf| elf.iter().map(|elf_box| elf_box.parse::<usize>()).sum())
+        .collect::<Result<Vec<usize>, ParseError>>()?;
+    calories.sort_by(|a, b| b.cmp(a));
+    Ok(calories)
+}
+
+fn top_elves(calories: &[usize], elves: usize) -> usize {
+    calories.iter().take(elves).sum()
+}
+
+struct CalorieCounting;
+impl Solution for CalorieCounting {
+    type Parsed = Vec<usize>;
+
+    fn parse(input_text: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input_text)
+    }
+    fn part1(calories: &Self::Parsed) -> Answer {
+        let elves = 1;
+        top_elves(calories, elves).into()
+    }
+    fn part2(calories: &Self::Parsed) -> Answer {
+        let elves = 3;
+        top_elves(calories, elves).into()
+    }
+}
+
+impl Generate for CalorieCounting {
+    fn generate(rng: &mut Rng, size: usize) -> Self::Parsed {
+        let mut calories = (0..size)
+            .map(|_| rng.range(1000..60000))
+            .collect::<Vec<usize>>();
+        calories.sort_by(|a, b| b.cmp(a));
+        calories
+    }
+    // Only the totals are kept, so each elf carries them in boxes of up to
+    // 8000 calories.
+    fn render(calories: &Self::Parsed) -> String {
+        calories
+            .iter()
+            .map(|total| {
+                let mut boxes = vec![];
+                let mut left = *total;
+                while left > 0 {
+                    let elf_box = left.min(1000 + left % 7000);
+                    boxes.push(elf_box.to_string());
+                    left -= elf_box;
+                }
+                boxes.join("\n")
+            })
+            .collect::<Vec<String>>()
+            .join("\n\n")
+    }
+}
+
+pub const DAY: Day = Day::new::<CalorieCounting>(1, "Calorie Counting");
@@ -0,0 +1,215 @@
+// Advent Of Code
+// https://adventofcode.com/2022/day/2
+//
+// --- Day 2: Rock Paper Scissors ---
+// Puzzle description: puzzles/day_02.md
+
+use crate::days::Day;
+use crate::generate::{Generate, Rng};
+use crate::parse::{self, Line, ParseError};
+use crate::{Answer, Solution};
+use std::cmp::Ordering;
+use std::slice::Iter;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum Move {
+    Rock = 1,
+    Paper = 2,
+    Scissors = 3,
+}
+impl Move {
+    fn from_line(str_move: Line) -> Result<Self, ParseError> {
+        const E_ROCK: &str = "A";
+        const E_PAPER: &str = "B";
+        const E_SCISSORS: &str = "C";
+        const P_ROCK: &str = "X";
+        const P_PAPER: &str = "Y";
+        const P_SCISSORS: &str = "Z";
+        match str_move.text() {
+            E_ROCK | P_ROCK => Ok(Self::Rock),
+            E_PAPER | P_PAPER => Ok(Self::Paper),
+            E_SCISSORS | P_SCISSORS => Ok(Self::Scissors),
+            _ => Err(str_move.error("a move: A, B, C, X, Y or Z")),
+        }
+    }
+    fn iter() -> Iter<'static, Move> {
+        static MOVES: [Move; 3] = [Move::Rock, Move::Paper, Move::Scissors];
+        MOVES.iter()
+    }
+    fn losing_move(&self) -> Move {
+        for mv in Self::iter() {
+            if mv.compare(self) == Ordering::Less {
+                return *mv;
+            }
+        }
+        panic!("No losing move!");
+    }
+    fn winning_move(&self) -> Move {
+        for mv in Self::iter() {
+            if mv.compare(self) == Ordering::Greater {
+                return *mv;
+            }
+        }
+        panic!("No winning_move move!");
+    }
+    fn compare(&self, other: &Self) -> Ordering {
+        match other {
+            _ if self == other => Ordering::Equal,
+            Move::Rock => match self {
+                Move::Paper => Ordering::Greater,
+                _ => Ordering::Less,
+            },
+            Move::Paper => match self {
+                Move::Scissors => Ordering::Greater,
+                _ => Ordering::Less,
+            },
+            Move::Scissors => match self {
+                Move::Rock => Ordering::Greater,
+                _ => Ordering::Less,
+            },
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Outcome {
+    Lose = 0,
+    Draw = 3,
+    Win = 6,
+}
+impl Outcome {
+    fn from_line(str_outcome: Line) -> Result<Self, ParseError> {
+        const P_LOSE: &str = "X";
+        const P_DRAW: &str = "Y";
+        const P_WIN: &str = "Z";
+        match str_outcome.text() {
+            P_LOSE => Ok(Outcome::Lose),
+            P_DRAW => Ok(Outcome::Draw),
+            P_WIN => Ok(Outcome::Win),
+            _ => Err(str_outcome.error("an outcome: X, Y or Z")),
+        }
+    }
+}
+
+#[allow(dead_code)]
+struct Game {
+    player_move: Move,
+    enemy_move: Move,
+    outcome: Outcome,
+    score: usize,
+}
+
+impl Game {
+    fn from_moves(player_move: Move, enemy_move: Move) -> Self {
+        let outcome = Self::play(&player_move, &enemy_move);
+        let score = Self::score(&player_move, &outcome);
+        Self {
+            player_move,
+            enemy_move,
+            outcome,
+            score,
+        }
+    }
+    fn from_outcome(enemy_move: Move, outcome: Outcome) -> Self {
+        let player_move = Self::find_move(&enemy_move, &outcome);
+        let score = Self::score(&player_move, &outcome);
+        Self {
+            player_move,
+            enemy_move,
+            outcome,
+            score,
+        }
+    }
+    fn play(player_move: &Move, enemy_move: &Move) -> Outcome {
+        match player_move.compare(enemy_move) {
+            Ordering::Equal => Outcome::Draw,
+            Ordering::Greater => Outcome::Win,
+            Ordering::Less => Outcome::Lose,
+        }
+    }
+    fn score(player_move: &Move, outcome: &Outcome) -> usize {
+        *outcome as usize + *player_move as usize
+    }
+    fn find_move(enemy_move: &Move, condition: &Outcome) -> Move {
+        match condition {
+            Outcome::Draw => *enemy_move,
+            Outcome::Lose => enemy_move.losing_move(),
+            Outcome::Win => enemy_move.winning_move(),
+        }
+    }
+}
+
+// The second column of the strategy guide is either our move (part one) or the
+// outcome of the round (part two), so we keep both readings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Strategy {
+    enemy_move: Move,
+    player_move: Move,
+    outcome: Outcome,
+}
+
+fn parse_input(input_text: &str) -> Result<Vec<Strategy>, ParseError> {
+    parse::lines(input_text.trim_end())
+        .map(|game_str| {
+            let (enemy_move, second) = game_str.trim().split_once(" ")?;
+            Ok(Strategy {
+                enemy_move: Move::from_line(enemy_move)?,
+                player_move: Move::from_line(second)?,
+                outcome: Outcome::from_line(second)?,
+            })
+        })
+        .collect()
+}
+
+struct RockPaperScissors;
+impl Solution for RockPaperScissors {
+    type Parsed = Vec<Strategy>;
+
+    fn parse(input_text: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input_text)
+    }
+    fn part1(guide: &Self::Parsed) -> Answer {
+        guide
+            .iter()
+            .map(|s| Game::from_moves(s.player_move, s.enemy_move).score)
+            .sum::<usize>()
+            .into()
+    }
+    fn part2(guide: &Self::Parsed) -> Answer {
+        guide
+            .iter()
+            .map(|s| Game::from_outcome(s.enemy_move, s.outcome).score)
+            .sum::<usize>()
+            .into()
+    }
+}
+
+impl Generate for RockPaperScissors {
+    fn generate(rng: &mut Rng, size: usize) -> Self::Parsed {
+        let moves = Move::iter().copied().collect::<Vec<Move>>();
+        let outcomes = [Outcome::Lose, Outcome::Draw, Outcome::Win];
+        (0..size)
+            .map(|_| {
+                let second = rng.range(0..3);
+                Strategy {
+                    enemy_move: *rng.pick(&moves),
+                    player_move: moves[second],
+                    outcome: outcomes[second],
+                }
+            })
+            .collect()
+    }
+    fn render(guide: &Self::Parsed) -> String {
+        guide
+            .iter()
+            .map(|s| {
+                let enemy = ["A", "B", "C"][s.enemy_move as usize - 1];
+                let second = ["X", "Y", "Z"][s.player_move as usize - 1];
+                format!("{} {}", enemy, second)
+            })
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+}
+
+pub const DAY: Day = Day::new::<RockPaperScissors>(2, "Rock Paper Scissors");
@@ -0,0 +1,106 @@
+// Advent Of Code
+// https://adventofcode.com/2022/day/3
+//
+// --- Day 3: Rucksack Reorganization ---
+// Puzzle description: puzzles/day_03.md
+
+use crate::days::Day;
+use crate::generate::{Generate, Rng};
+use crate::parse::{self, Line, ParseError};
+use crate::{Answer, Solution};
+
+fn string_intersection((str_1, str_2): (&str, &str)) -> String {
+    let mut str_1_clone = str_1.chars().collect::<Vec<char>>();
+    let mut str_2_clone = str_2.chars().collect::<Vec<char>>();
+    str_1_clone.sort();
+    str_2_clone.sort();
+    str_1_clone.dedup();
+    str_2_clone.dedup();
+
+    str_1_clone
+        .into_iter()
+        .filter(|c| str_2_clone.contains(c))
+        .collect()
+}
+
+fn parse_priority(item: char) -> usize {
+    let priorities: Vec<char> = ('a'..='z').chain('A'..='Z').collect();
+    priorities.iter().position(|p| *p == item).expect("Oh boi!") + 1
+}
+
+fn parse_rucksack(line: Line) -> Result<String, ParseError> {
+    match line.text().find(|c: char| !c.is_ascii_alphabetic()) {
+        Some(i) => Err(line.skip(i).error("an item from a to z or A to Z")),
+        None => Ok(line.text().to_string()),
+    }
+}
+
+struct RucksackReorganization;
+impl Solution for RucksackReorganization {
+    type Parsed = Vec<String>;
+
+    fn parse(input_text: &str) -> Result<Self::Parsed, ParseError> {
+        parse::lines(input_text).map(parse_rucksack).collect()
+    }
+    fn part1(rucksacks: &Self::Parsed) -> Answer {
+        rucksacks
+            .iter()
+            .map(|s| s.split_at(s.len() / 2))
+            .map(|(s1, s2)| string_intersection((s1, s2)))
+            .map(|items| items.chars().map(parse_priority).sum::<usize>())
+            .sum::<usize>()
+            .into()
+    }
+    fn part2(rucksacks: &Self::Parsed) -> Answer {
+        let elves = 3;
+        rucksacks
+            .chunks(elves)
+            .map(|sacks| {
+                sacks
+                    .iter()
+                    .cloned()
+                    .reduce(|acc, item| string_intersection((&acc, &item)))
+                    .unwrap()
+            })
+            .map(|items| items.chars().map(parse_priority).sum::<usize>())
+            .sum::<usize>()
+            .into()
+    }
+}
+
+impl Generate for RucksackReorganization {
+    // Groups of three elves sharing a badge, each with an item in both
+    // compartments.
+    fn generate(rng: &mut Rng, size: usize) -> Self::Parsed {
+        let items = ('a'..='z').chain('A'..='Z').collect::<Vec<char>>();
+        let groups = size.div_ceil(3);
+        (0..groups * 3)
+            .map(|_| {
+                let half = rng.range(4..17);
+                let mut sack = (0..half * 2)
+                    .map(|_| *rng.pick(&items))
+                    .collect::<Vec<char>>();
+                sack[half + rng.range(0..half)] = sack[rng.range(0..half)];
+                sack
+            })
+            .collect::<Vec<Vec<char>>>()
+            .chunks_mut(3)
+            .flat_map(|group| {
+                let badge = *rng.pick(&items);
+                group
+                    .iter_mut()
+                    .map(|sack| {
+                        let at = rng.range(0..sack.len());
+                        sack[at] = badge;
+                        sack.iter().collect::<String>()
+                    })
+                    .collect::<Vec<String>>()
+            })
+            .collect()
+    }
+    fn render(rucksacks: &Self::Parsed) -> String {
+        rucksacks.join("\n")
+    }
+}
+
+pub const DAY: Day = Day::new::<RucksackReorganization>(3, "Rucksack Reorganization");
@@ -0,0 +1,80 @@
+// Advent Of Code
+// https://adventofcode.com/2022/day/4
+//
+// --- Day 4: Camp Cleanup ---
+// Puzzle description: puzzles/day_04.md
+
+use crate::days::Day;
+use crate::generate::{Generate, Rng};
+use crate::parse::{self, Line, ParseError};
+use crate::{Answer, Solution};
+use std::ops::Range;
+
+type Pair = (Range<usize>, Range<usize>);
+
+fn fully_contains(r0: &Range<usize>, r1: &Range<usize>) -> bool {
+    (r0.contains(&r1.start) && r0.contains(&(r1.end - 1)))
+        || (r1.contains(&r0.start) && r1.contains(&(r0.end - 1)))
+}
+
+fn any_overlap(r0: &Range<usize>, r1: &Range<usize>) -> bool {
+    r0.contains(&r1.start)
+        || r0.contains(&(r1.end - 1))
+        || r1.contains(&r0.start)
+        || r1.contains(&(r0.end - 1))
+}
+
+fn parse_range(elf_ids: Line) -> Result<Range<usize>, ParseError> {
+    let (start, end) = elf_ids.split_once("-")?;
+    Ok(Range {
+        start: start.parse()?,
+        end: end.parse::<usize>()? + 1, // Ranges excludes the end value
+    })
+}
+
+fn parse_input(input_text: &str) -> Result<Vec<Pair>, ParseError> {
+    parse::lines(input_text.trim_end())
+        .map(|line| {
+            let (elf_1, elf_2) = line.split_once(",")?;
+            Ok((parse_range(elf_1)?, parse_range(elf_2)?))
+        })
+        .collect()
+}
+
+fn count_pairs(pairs: &[Pair], overlap_fn: fn(&Range<usize>, &Range<usize>) -> bool) -> usize {
+    pairs.iter().filter(|(r0, r1)| overlap_fn(r0, r1)).count()
+}
+
+struct CampCleanup;
+impl Solution for CampCleanup {
+    type Parsed = Vec<Pair>;
+
+    fn parse(input_text: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input_text)
+    }
+    fn part1(pairs: &Self::Parsed) -> Answer {
+        count_pairs(pairs, fully_contains).into()
+    }
+    fn part2(pairs: &Self::Parsed) -> Answer {
+        count_pairs(pairs, any_overlap).into()
+    }
+}
+
+impl Generate for CampCleanup {
+    fn generate(rng: &mut Rng, size: usize) -> Self::Parsed {
+        let mut sections = || {
+            let start = rng.range(1..100);
+            start..rng.range(start..100) + 1
+        };
+        (0..size).map(|_| (sections(), sections())).collect()
+    }
+    fn render(pairs: &Self::Parsed) -> String {
+        pairs
+            .iter()
+            .map(|(r0, r1)| format!("{}-{},{}-{}", r0.start, r0.end - 1, r1.start, r1.end - 1))
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+}
+
+pub const DAY: Day = Day::new::<CampCleanup>(4, "Camp Cleanup");
@@ -0,0 +1,203 @@
+// Advent Of Code
+// https://adventofcode.com/2022/day/5
+//
+// --- Day 5: Supply Stacks ---
+// Puzzle description: puzzles/day_05.md
+
+use crate::days::Day;
+use crate::generate::{Generate, Rng};
+use crate::parse::{self, Line, ParseError};
+use crate::{Answer, Solution};
+
+#[derive(Debug, PartialEq, Eq)]
+struct Move {
+    boxes: usize,
+    from: usize,
+    to: usize,
+}
+type Moves = Vec<Move>;
+type CraneFn = fn(&mut Boxes, &Move);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Boxes {
+    storage: Vec<Vec<char>>,
+}
+impl Boxes {
+    fn run_crane(&mut self, crane: CraneFn, moves: &Moves) {
+        moves.iter().for_each(|mv| {
+            crane(self, mv);
+        });
+    }
+    fn get_top_boxes(&self) -> String {
+        self.storage
+            .iter()
+            .filter_map(|pile| pile.last())
+            .collect::<String>()
+    }
+}
+
+fn crane9000(boxes: &mut Boxes, mv: &Move) {
+    for _ in 0..mv.boxes {
+        let bx = boxes.storage[mv.from].pop().unwrap();
+        boxes.storage[mv.to].push(bx);
+    }
+}
+
+fn crane9001(boxes: &mut Boxes, mv: &Move) {
+    let slice_start = boxes.storage[mv.from].len() - mv.boxes;
+    let bxs = boxes.storage[mv.from]
+        .drain(slice_start..)
+        .collect::<Vec<_>>();
+    boxes.storage[mv.to].extend(bxs);
+}
+
+fn parse_move(line: Line, piles: usize) -> Result<Move, ParseError> {
+    // Piles are numbered from 1 in the input.
+    let pile = |label: &str| -> Result<usize, ParseError> {
+        let pile = line.field::<usize>(label)?;
+        if (1..=piles).contains(&pile) {
+            Ok(pile - 1)
+        } else {
+            Err(line
+                .after(label)?
+                .error(format!("a pile from 1 to {}", piles)))
+        }
+    };
+    Ok(Move {
+        boxes: line.field("move ")?,
+        from: pile("from ")?,
+        to: pile("to ")?,
+    })
+}
+
+fn parse_input(input_text: &str) -> Result<(Boxes, Moves), ParseError> {
+    let blocks = parse::blocks(input_text);
+    let [storage, moves] = &blocks[..] else {
+        return Err(parse::first_line(input_text).error("the piles, a blank line and the moves"));
+    };
+    // The last line of the drawing numbers the piles.
+    let (numbers, layers) = storage.split_last().unwrap();
+    let piles = numbers.ints::<usize>().len();
+    if piles == 0 {
+        return Err(numbers.error("pile numbers"));
+    }
+    let layers = layers
+        .iter()
+        .map(|line| {
+            line.text()
+                .as_bytes()
+                .chunks(4)
+                .map(|chunk| chunk.get(1).map_or(' ', |c| *c as char))
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    let storage = (0..piles)
+        .map(|i| {
+            layers
+                .iter()
+                .rev()
+                .filter_map(|layer| layer.get(i).copied())
+                .filter(|item| item != &' ')
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<Vec<char>>>();
+    // Both cranes take crates off a pile as long as it has enough of them.
+    let mut heights = storage.iter().map(Vec::len).collect::<Vec<usize>>();
+    let boxes = Boxes { storage };
+    let moves = moves
+        .iter()
+        .map(|line| {
+            let mv = parse_move(*line, piles)?;
+            if mv.boxes > heights[mv.from] {
+                return Err(line.after("move ")?.error(format!(
+                    "no more crates than the {} on pile {}",
+                    heights[mv.from],
+                    mv.from + 1
+                )));
+            }
+            heights[mv.from] -= mv.boxes;
+            heights[mv.to] += mv.boxes;
+            Ok(mv)
+        })
+        .collect::<Result<Moves, ParseError>>()?;
+    Ok((boxes, moves))
+}
+
+fn operate(boxes: &Boxes, moves: &Moves, crane: CraneFn) -> String {
+    let mut boxes = boxes.clone();
+    boxes.run_crane(crane, moves);
+    boxes.get_top_boxes()
+}
+
+struct SupplyStacks;
+impl Solution for SupplyStacks {
+    type Parsed = (Boxes, Moves);
+
+    fn parse(input_text: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input_text)
+    }
+    fn part1((boxes, moves): &Self::Parsed) -> Answer {
+        operate(boxes, moves, crane9000).into()
+    }
+    fn part2((boxes, moves): &Self::Parsed) -> Answer {
+        operate(boxes, moves, crane9001).into()
+    }
+}
+
+impl Generate for SupplyStacks {
+    // Moves never empty a pile, so there's always a crate on top to read.
+    fn generate(rng: &mut Rng, size: usize) -> Self::Parsed {
+        let piles = rng.range(2..10);
+        let crates = ('A'..='Z').collect::<Vec<char>>();
+        let storage = (0..piles)
+            .map(|pile| {
+                let height = rng.range(1 + usize::from(pile == 0)..8);
+                (0..height).map(|_| *rng.pick(&crates)).collect()
+            })
+            .collect::<Vec<Vec<char>>>();
+        let mut heights = storage.iter().map(Vec::len).collect::<Vec<usize>>();
+        let moves = (0..size)
+            .map(|_| {
+                let from = *rng.pick(&(0..piles).filter(|p| heights[*p] > 1).collect::<Vec<_>>());
+                let to = *rng.pick(&(0..piles).filter(|p| *p != from).collect::<Vec<_>>());
+                let boxes = rng.range(1..heights[from]);
+                heights[from] -= boxes;
+                heights[to] += boxes;
+                Move { boxes, from, to }
+            })
+            .collect();
+        (Boxes { storage }, moves)
+    }
+    fn render((boxes, moves): &Self::Parsed) -> String {
+        let height = boxes.storage.iter().map(Vec::len).max().unwrap_or(0);
+        let mut lines = (0..height)
+            .rev()
+            .map(|level| {
+                boxes
+                    .storage
+                    .iter()
+                    .map(|pile| {
+                        pile.get(level)
+                            .map_or("   ".to_string(), |c| format!("[{}]", c))
+                    })
+                    .collect::<Vec<String>>()
+                    .join(" ")
+                    .trim_end()
+                    .to_string()
+            })
+            .collect::<Vec<String>>();
+        let numbers = (1..=boxes.storage.len())
+            .map(|pile| format!(" {} ", pile))
+            .collect::<Vec<String>>();
+        lines.push(numbers.join(" ").trim_end().to_string());
+        lines.push(String::new());
+        lines.extend(
+            moves
+                .iter()
+                .map(|mv| format!("move {} from {} to {}", mv.boxes, mv.from + 1, mv.to + 1)),
+        );
+        lines.join("\n")
+    }
+}
+
+pub const DAY: Day = Day::new::<SupplyStacks>(5, "Supply Stacks");
@@ -0,0 +1,67 @@
+// Advent Of Code
+// https://adventofcode.com/2022/day/6
+//
+// --- Day 6: Tuning Trouble ---
+// Puzzle description: puzzles/day_06.md
+
+use crate::days::Day;
+use crate::generate::{Generate, Rng};
+use crate::{Answer, ParseError, Solution};
+
+fn find_marker(input_text: &str, window_size: usize) -> Option<usize> {
+    input_text
+        .as_bytes()
+        .windows(window_size)
+        .position(|w| {
+            let mut window = w.iter().collect::<Vec<_>>().clone();
+            window.sort();
+            window.dedup();
+            window.len() == window_size
+        })
+        .map(|position| position + window_size)
+}
+
+fn marker_answer(datastream: &str, window_size: usize) -> Answer {
+    find_marker(datastream, window_size).map_or("no marker".into(), Answer::from)
+}
+
+struct TuningTrouble;
+impl Solution for TuningTrouble {
+    type Parsed = String;
+
+    fn parse(input_text: &str) -> Result<Self::Parsed, ParseError> {
+        Ok(input_text.trim_end().to_string())
+    }
+    fn part1(datastream: &Self::Parsed) -> Answer {
+        let window_size: usize = 4;
+        marker_answer(datastream, window_size)
+    }
+    fn part2(datastream: &Self::Parsed) -> Answer {
+        let window_size: usize = 14;
+        marker_answer(datastream, window_size)
+    }
+}
+
+impl Generate for TuningTrouble {
+    // Noise with a start-of-message marker somewhere in it.
+    fn generate(rng: &mut Rng, size: usize) -> Self::Parsed {
+        let letters = ('a'..='z').collect::<Vec<char>>();
+        let length = 14 + size * 20;
+        let mut datastream = (0..length)
+            .map(|_| {
+                let letters = &letters[..rng.range(2..letters.len())];
+                *rng.pick(letters)
+            })
+            .collect::<Vec<char>>();
+        let mut marker = letters.clone();
+        rng.shuffle(&mut marker);
+        let at = rng.range(0..length - 13);
+        datastream.splice(at..at + 14, marker[..14].iter().copied());
+        datastream.into_iter().collect()
+    }
+    fn render(datastream: &Self::Parsed) -> String {
+        datastream.clone()
+    }
+}
+
+pub const DAY: Day = Day::new::<TuningTrouble>(6, "Tuning Trouble");
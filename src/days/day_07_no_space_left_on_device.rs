@@ -0,0 +1,258 @@
+// Advent Of Code
+// https://adventofcode.com/2022/day/7
+//
+// --- Day 7: No Space Left On Device ---
+// Puzzle description: puzzles/day_07.md
+
+use crate::days::Day;
+use crate::generate::{Generate, Rng};
+use crate::parse::{self, Line, ParseError};
+use crate::{Answer, Solution};
+
+const SHELL_ANCHOR: &str = "$ ";
+const LS: &str = "ls";
+const CD: &str = "cd";
+const DIR_ANCHOR: &str = "dir";
+const PARENT_DIR_ANCHOR: &str = "..";
+const ROOT_DIR_ANCHOR: &str = "/";
+
+#[derive(Debug, PartialEq, Eq)]
+struct Root {
+    dirs: Vec<Dir>,
+    root_idx: usize,
+    cwd_idx: usize,
+}
+
+impl Root {
+    fn new() -> Self {
+        let root_dir = Dir::new(ROOT_DIR_ANCHOR, 0);
+        Root {
+            dirs: vec![root_dir],
+            root_idx: 0,
+            cwd_idx: 0,
+        }
+    }
+
+    fn new_file(&mut self, name: &str, size: usize) {
+        self.get_cwd().files.push(File {
+            name: name.to_string(),
+            size,
+        });
+    }
+    fn new_dir(&mut self, name: &str) {
+        let index = self.dirs.len();
+        let new_dir = Dir::new(name, self.cwd_idx);
+        self.dirs.push(new_dir);
+        self.get_cwd().dirs.push(index);
+    }
+    fn get_cwd(&mut self) -> &mut Dir {
+        &mut self.dirs[self.cwd_idx]
+    }
+    fn get_root_dir(&self) -> &Dir {
+        &self.dirs[self.root_idx]
+    }
+    fn get_dir_by_index(&mut self, index: usize) -> &mut Dir {
+        &mut self.dirs[index]
+    }
+    fn change_dir(&mut self, name: &str) -> Option<()> {
+        match name {
+            ROOT_DIR_ANCHOR => self.cwd_idx = self.root_idx,
+            PARENT_DIR_ANCHOR => self.cwd_idx = self.get_cwd().parent,
+            _ => {
+                self.cwd_idx = self.find_dir_index(name)?;
+            }
+        }
+        Some(())
+    }
+    fn find_dir_index(&mut self, name: &str) -> Option<usize> {
+        let cwd = self.get_cwd().clone();
+        cwd.dirs
+            .iter()
+            .find(|i| self.get_dir_by_index(**i).name == name)
+            .copied()
+    }
+    fn compute_dir_sizes(&mut self) {
+        for (i, dir) in self.dirs.clone().iter_mut().enumerate().rev() {
+            let file_sizes = dir.files.iter_mut().fold(0, |acc, f| acc + f.size);
+            let dir_sizes: usize = dir
+                .dirs
+                .clone()
+                .into_iter()
+                .map(|j| self.dirs[j].size)
+                .sum();
+            self.dirs[i].size = file_sizes + dir_sizes;
+        }
+    }
+    fn list_dir_sizes(&self) -> Vec<usize> {
+        let mut sizes = self.dirs.iter().map(|d| d.size).collect::<Vec<usize>>();
+        sizes.sort();
+        sizes
+    }
+    // Replays one line of the terminal session: a command or a line of `ls`
+    // output.
+    fn parse_line(&mut self, line: Line) -> Result<(), ParseError> {
+        if let Ok(full_command) = line.strip_prefix(SHELL_ANCHOR) {
+            let (cmd_str, args) = full_command
+                .split_once(" ")
+                .unwrap_or((full_command, full_command.skip(full_command.text().len())));
+            match cmd_str.text() {
+                CD => self
+                    .change_dir(args.text())
+                    .ok_or_else(|| args.error("a directory listed before"))?,
+                LS => (),
+                _ => return Err(cmd_str.error("`cd` or `ls`")),
+            }
+        } else {
+            let (head, tail) = line.split_once(" ")?;
+            match head.text() {
+                DIR_ANCHOR => self.new_dir(tail.text()),
+                _ => self.new_file(tail.text(), head.parse()?),
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Dir {
+    name: String,
+    parent: usize,
+    dirs: Vec<usize>,
+    files: Vec<File>,
+    size: usize,
+}
+
+impl Dir {
+    fn new(name: &str, parent: usize) -> Self {
+        Dir {
+            name: name.to_string(),
+            parent,
+            dirs: vec![],
+            files: vec![],
+            size: 0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct File {
+    name: String,
+    size: usize,
+}
+
+fn parse_input(input_text: &str) -> Result<Root, ParseError> {
+    let mut root = Root::new();
+    for line in parse::lines(input_text.trim_end()) {
+        root.parse_line(line)?;
+    }
+    root.compute_dir_sizes();
+    Ok(root)
+}
+
+struct NoSpaceLeftOnDevice;
+impl Solution for NoSpaceLeftOnDevice {
+    type Parsed = Root;
+
+    fn parse(input_text: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input_text)
+    }
+    fn part1(root: &Self::Parsed) -> Answer {
+        let at_most_pt1: usize = 100000;
+        root.list_dir_sizes()
+            .into_iter()
+            .filter(|a| a < &at_most_pt1)
+            .sum::<usize>()
+            .into()
+    }
+    fn part2(root: &Self::Parsed) -> Answer {
+        let total_disk: usize = 70000000;
+        let at_least: usize = 30000000;
+        let root_size = root.get_root_dir().size;
+        // Nothing to free on a disk with room to spare.
+        let free_at_most = (root_size + at_least).saturating_sub(total_disk);
+        root.list_dir_sizes()
+            .into_iter()
+            .find(|a| a > &free_at_most && a != &root_size)
+            .map_or("no directory frees enough space".into(), Answer::from)
+    }
+}
+
+// Gives the current directory some subdirectories and files, then does the
+// same in each subdirectory, `budget` directories in all. Directories are
+// created in the order a terminal session listing them would.
+fn fill_dir(root: &mut Root, rng: &mut Rng, budget: &mut usize, max_file: usize) {
+    let cwd = root.cwd_idx;
+    let mut initials = ('a'..='z').collect::<Vec<char>>();
+    rng.shuffle(&mut initials);
+    let dirs = rng.range(usize::from(cwd == root.root_idx)..4).min(*budget);
+    *budget -= dirs;
+    for initial in &initials[..dirs] {
+        root.new_dir(&random_name(rng, *initial));
+    }
+    for _ in 0..rng.range(0..4) {
+        let name = format!(
+            "{}.{}",
+            random_name(rng, initials[25]),
+            random_name(rng, 't')
+        );
+        root.new_file(&name, rng.range(1..max_file));
+    }
+    for dir in root.get_cwd().dirs.clone() {
+        root.cwd_idx = dir;
+        fill_dir(root, rng, budget, max_file);
+    }
+    root.cwd_idx = cwd;
+}
+
+fn random_name(rng: &mut Rng, initial: char) -> String {
+    let mut name = initial.to_string();
+    name.extend((0..rng.range(0..7)).map(|_| (b'a' + rng.range(0..26) as u8) as char));
+    name
+}
+
+fn render_dir(root: &Root, index: usize, session: &mut Vec<String>) {
+    let dir = &root.dirs[index];
+    session.push(format!("{}{}", SHELL_ANCHOR, LS));
+    for sub_dir in &dir.dirs {
+        session.push(format!("{} {}", DIR_ANCHOR, root.dirs[*sub_dir].name));
+    }
+    for file in &dir.files {
+        session.push(format!("{} {}", file.size, file.name));
+    }
+    for sub_dir in &dir.dirs {
+        let name = &root.dirs[*sub_dir].name;
+        session.push(format!("{}{} {}", SHELL_ANCHOR, CD, name));
+        render_dir(root, *sub_dir, session);
+        session.push(format!("{}{} {}", SHELL_ANCHOR, CD, PARENT_DIR_ANCHOR));
+    }
+}
+
+impl Generate for NoSpaceLeftOnDevice {
+    // Files take between 40 and 60 million of the 70 million disk, most of it
+    // in a single big file, so there's always a directory worth deleting.
+    fn generate(rng: &mut Rng, size: usize) -> Self::Parsed {
+        let mut root = Root::new();
+        let mut budget = size.max(1);
+        let max_file = (10_000_000 / (4 * budget)).clamp(2, 300_000);
+        fill_dir(&mut root, rng, &mut budget, max_file);
+        let used = root
+            .dirs
+            .iter()
+            .flat_map(|d| &d.files)
+            .map(|f| f.size)
+            .sum::<usize>();
+        root.dirs[1].files.push(File {
+            name: random_name(rng, 'z'),
+            size: 40_000_000 - used + rng.range(1..20_000_000),
+        });
+        root.compute_dir_sizes();
+        root
+    }
+    fn render(root: &Self::Parsed) -> String {
+        let mut session = vec![format!("{}{} {}", SHELL_ANCHOR, CD, ROOT_DIR_ANCHOR)];
+        render_dir(root, root.root_idx, &mut session);
+        session.join("\n")
+    }
+}
+
+pub const DAY: Day = Day::new::<NoSpaceLeftOnDevice>(7, "No Space Left On Device");
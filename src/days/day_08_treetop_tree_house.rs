@@ -0,0 +1,98 @@
+// Advent Of Code
+// https://adventofcode.com/2022/day/8
+//
+// --- Day 8: Treetop Tree House ---
+// Puzzle description: puzzles/day_08.md
+
+use crate::days::Day;
+use crate::generate::{Generate, Rng};
+use crate::grid::Grid;
+use crate::parse::{self, ParseError};
+use crate::{Answer, Solution};
+
+#[derive(Debug, PartialEq, Eq)]
+struct Forest {
+    trees: Grid<u8>,
+}
+impl Forest {
+    // Every row and column in both directions, as the trees met walking in
+    // from one border.
+    fn walks(&self) -> impl Iterator<Item = Vec<(usize, usize)>> {
+        let (width, height) = (self.trees.width(), self.trees.height());
+        let rows = (0..height).map(move |y| (0..width).map(|x| (x, y)).collect::<Vec<_>>());
+        let columns = (0..width).map(move |x| (0..height).map(|y| (x, y)).collect::<Vec<_>>());
+        rows.chain(columns)
+            .flat_map(|walk| [walk.iter().rev().copied().collect(), walk])
+    }
+
+    fn visible_from_borders(&self) -> usize {
+        let mut visible = self.trees.map(|_| false);
+        for walk in self.walks() {
+            let mut tallest = None;
+            for position in walk {
+                let tree = Some(self.trees[position]);
+                if tree > tallest {
+                    visible[position] = true;
+                    tallest = tree;
+                }
+            }
+        }
+        visible.iter().filter(|(_, visible)| **visible).count()
+    }
+
+    // Looking back along a walk, a tree sees as far as the nearest tree at
+    // least as tall, or the border.
+    fn scenic_scores(&self) -> Vec<usize> {
+        let mut scores = self.trees.map(|_| 1);
+        for walk in self.walks() {
+            // Where along the walk the last tree of each height stands.
+            let mut last_seen = [None; 10];
+            for (i, position) in walk.into_iter().enumerate() {
+                let tree = self.trees[position] as usize;
+                let blocker = last_seen[tree..].iter().flatten().max().copied();
+                scores[position] *= i - blocker.unwrap_or(0);
+                last_seen[tree] = Some(i);
+            }
+        }
+        scores.iter().map(|(_, score)| *score).collect()
+    }
+}
+
+fn parse_input(input_text: &str) -> Result<Forest, ParseError> {
+    let trees = parse::grid(input_text, "a tree height from 0 to 9", |c| {
+        c.to_digit(10).map(|d| d as u8)
+    })?;
+    Ok(Forest { trees })
+}
+
+struct TreetopTreeHouse;
+impl Solution for TreetopTreeHouse {
+    type Parsed = Forest;
+
+    fn parse(input_text: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input_text)
+    }
+    fn part1(forest: &Self::Parsed) -> Answer {
+        forest.visible_from_borders().into()
+    }
+    fn part2(forest: &Self::Parsed) -> Answer {
+        let scenic_scores = forest.scenic_scores();
+        scenic_scores.into_iter().max().unwrap().into()
+    }
+}
+
+impl Generate for TreetopTreeHouse {
+    // A square forest of random heights, `size` trees on a side.
+    fn generate(rng: &mut Rng, size: usize) -> Self::Parsed {
+        let side = size.max(1);
+        let cells = (0..side * side).map(|_| rng.range(0..10) as u8).collect();
+        Forest {
+            trees: Grid::from_cells(side, cells),
+        }
+    }
+    fn render(forest: &Self::Parsed) -> String {
+        forest.trees.render(|tree| char::from(b'0' + tree))
+    }
+}
+
+pub const DAY: Day = Day::new::<TreetopTreeHouse>(8, "Treetop Tree House");
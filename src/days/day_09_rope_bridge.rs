@@ -0,0 +1,160 @@
+// Advent Of Code
+// https://adventofcode.com/2022/day/9
+//
+// --- Day 9: Rope Bridge ---
+// Puzzle description: puzzles/day_09.md
+
+use crate::days::Day;
+use crate::generate::{Generate, Rng};
+use crate::geom::{Direction4, Point2};
+use crate::grid::Grid;
+use crate::parse::{self, Line, ParseError};
+use crate::trace;
+use crate::{Answer, Solution};
+
+#[derive(Debug, PartialEq, Eq)]
+struct Move {
+    direction: Direction4,
+    steps: i32,
+}
+
+type Pos = Point2<i32>;
+
+fn parse_move(move_str: Line) -> Result<Move, ParseError> {
+    let (direction, steps) = move_str.split_once(" ")?;
+    Ok(Move {
+        direction: match direction.text() {
+            "U" => Direction4::Up,
+            "L" => Direction4::Left,
+            "D" => Direction4::Down,
+            "R" => Direction4::Right,
+            _ => return Err(direction.error("a direction: U, L, D or R")),
+        },
+        steps: steps.parse()?,
+    })
+}
+
+fn parse_input(input_text: &str) -> Result<Vec<Move>, ParseError> {
+    parse::lines(input_text).map(parse_move).collect()
+}
+
+fn move_rope(moves: &[Move], knots: u32) -> Vec<Vec<Pos>> {
+    let mut rope = vec![vec![Pos::new(0, 0)]; knots.try_into().unwrap()];
+    let mut head = rope[0][0];
+
+    moves.iter().for_each(|mv| {
+        (0..mv.steps).for_each(|_| {
+            head = head + mv.direction;
+            rope[0].push(head);
+            (1..knots as usize).for_each(|k| {
+                let prev_knot = *rope[k - 1].last().unwrap();
+                let mut cur_knot = *rope[k].last().unwrap();
+                cur_knot = follow_tail(&cur_knot, &prev_knot);
+                rope[k].push(cur_knot);
+            })
+        })
+    });
+    rope
+}
+
+fn follow_tail(tail: &Pos, head: &Pos) -> Pos {
+    if tail.chebyshev(head) <= 1 {
+        *tail
+    } else {
+        *tail + (*head - *tail).signum()
+    }
+}
+
+fn board_size(positions: &[Pos]) -> (i32, i32, i32, i32, i32, i32) {
+    let (x0, x1) = (
+        positions.iter().map(|p| p.x).min().unwrap(),
+        positions.iter().map(|p| p.x).max().unwrap(),
+    );
+    let (y0, y1) = (
+        positions.iter().map(|p| p.y).min().unwrap(),
+        positions.iter().map(|p| p.y).max().unwrap(),
+    );
+    let (h, w) = (y1 - y0 + 1, x1 - x0 + 1);
+    (x0, x1, y0, y1, w, h)
+}
+
+fn pos_frequency(positions: &[Pos]) -> Grid<u64> {
+    let (x0, _, y0, _, w, h) = board_size(positions);
+    let mut freq = Grid::new(w as usize, h as usize, 0);
+    positions.iter().for_each(|p| {
+        freq[((p.x - x0) as usize, (p.y - y0) as usize)] += 1;
+    });
+    freq
+}
+
+fn trace_rope(rope: &[Vec<Pos>]) {
+    let (x0, _x1, y0, _y1, w, h) = board_size(&rope[0]);
+    let knots = rope.len();
+    let moves = rope[0].len();
+    let mut icons = (b'0'..=b'9').map(char::from).collect::<Vec<_>>();
+    icons[0] = 'H';
+    icons[knots - 1] = 'T';
+    (0..moves).for_each(|m| {
+        let mut board = Grid::new(w as usize, h as usize, '.');
+        (0..knots).rev().for_each(|k| {
+            let mv = rope[k][m];
+            board[((mv.x - x0) as usize, (mv.y - y0) as usize)] = icons[k];
+        });
+        trace!(Trace, "Move {}:\n{}", m, board);
+    })
+}
+
+fn tail_visits(moves: &[Move], knots: u32) -> u64 {
+    let rope = move_rope(moves, knots);
+    let t_freq = pos_frequency(rope.last().unwrap());
+    if trace::enabled(trace::Level::Trace, module_path!()) {
+        trace_rope(&rope);
+    }
+    const ONCE: u64 = 1;
+    t_freq.iter().filter(|(_, c)| **c >= ONCE).count() as u64
+}
+
+struct RopeBridge;
+impl Solution for RopeBridge {
+    type Parsed = Vec<Move>;
+
+    fn parse(input_text: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input_text)
+    }
+    fn part1(moves: &Self::Parsed) -> Answer {
+        const KNOTS: u32 = 2;
+        tail_visits(moves, KNOTS).into()
+    }
+    fn part2(moves: &Self::Parsed) -> Answer {
+        const KNOTS: u32 = 10;
+        tail_visits(moves, KNOTS).into()
+    }
+}
+
+impl Generate for RopeBridge {
+    fn generate(rng: &mut Rng, size: usize) -> Self::Parsed {
+        (0..size)
+            .map(|_| Move {
+                direction: *rng.pick(&Direction4::ALL),
+                steps: rng.range(1..20) as i32,
+            })
+            .collect()
+    }
+    fn render(moves: &Self::Parsed) -> String {
+        moves
+            .iter()
+            .map(|mv| {
+                let direction = match mv.direction {
+                    Direction4::Up => "U",
+                    Direction4::Left => "L",
+                    Direction4::Down => "D",
+                    Direction4::Right => "R",
+                };
+                format!("{} {}", direction, mv.steps)
+            })
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+}
+
+pub const DAY: Day = Day::new::<RopeBridge>(9, "Rope Bridge");
@@ -0,0 +1,176 @@
+// Advent Of Code
+// https://adventofcode.com/2022/day/10
+//
+// --- Day 10: Cathode-Ray Tube ---
+// Puzzle description: puzzles/day_10.md
+
+use crate::days::Day;
+use crate::generate::{Generate, Rng};
+use crate::parse::{self, Line, ParseError};
+use crate::{Answer, Solution};
+
+#[derive(Debug, PartialEq, Eq)]
+enum Instr {
+    Addx,
+    Noop,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Instruction {
+    instruction: Instr,
+    argument: i64,
+}
+impl Instruction {
+    fn from_line(line: Line) -> Result<Self, ParseError> {
+        let (instr, arg) = line
+            .split_once(" ")
+            .unwrap_or((line, line.skip(line.text().len())));
+        match instr.text() {
+            "addx" => Ok(Instruction {
+                instruction: Instr::Addx,
+                argument: arg.parse::<i64>()?,
+            }),
+            "noop" => Ok(Instruction {
+                instruction: Instr::Noop,
+                argument: 0,
+            }),
+            _ => Err(instr.error("an instruction: `addx` or `noop`")),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+struct Register {
+    value: i64,
+    cycles: u64,
+}
+
+#[derive(Debug, Copy, Clone)]
+struct Cpu {
+    register_x: Register,
+}
+
+impl Cpu {
+    fn noop(&mut self) -> Vec<Register> {
+        let cur_reg = self.register_x;
+        self.register_x.cycles += 1;
+        vec![cur_reg]
+    }
+    fn addx(&mut self, argument: i64) -> Vec<Register> {
+        let cur_reg = self.register_x;
+        let mut next_reg = self.register_x;
+        next_reg.cycles += 1;
+        self.register_x.cycles += 2;
+        self.register_x.value += argument;
+        vec![cur_reg, next_reg]
+    }
+    fn execute(&mut self, i: &Instruction) -> Vec<Register> {
+        match i.instruction {
+            Instr::Addx => self.addx(i.argument),
+            Instr::Noop => self.noop(),
+        }
+    }
+}
+
+fn run_program(instructions: &[Instruction]) -> Vec<Register> {
+    let mut cpu = Cpu {
+        register_x: Register {
+            value: 1, // Register starts at 1
+            cycles: 0,
+        },
+    };
+    let mut ret = cpu.noop(); // Starts at cycle 1
+    ret.extend(
+        instructions
+            .iter()
+            .flat_map(|instruction| cpu.execute(instruction))
+            .collect::<Vec<_>>(),
+    );
+    ret
+}
+
+fn parse_instructions(input_text: &str) -> Result<Vec<Instruction>, ParseError> {
+    parse::lines(input_text)
+        .map(Instruction::from_line)
+        .collect()
+}
+
+struct CathodeRayTube;
+impl Solution for CathodeRayTube {
+    type Parsed = Vec<Instruction>;
+
+    fn parse(input_text: &str) -> Result<Self::Parsed, ParseError> {
+        parse_instructions(input_text)
+    }
+    fn part1(instructions: &Self::Parsed) -> Answer {
+        let history = run_program(instructions);
+        const STARTING_CYCLE: usize = 20;
+        const CHUNK_SIZE: usize = 40;
+        // A program that stops before cycle 20 has no signal strengths.
+        let signal_strength = history
+            .get(STARTING_CYCLE..)
+            .unwrap_or_default()
+            .chunks(CHUNK_SIZE)
+            .map(|chunk| chunk[0].value * chunk[0].cycles as i64)
+            .sum::<i64>();
+        signal_strength.into()
+    }
+    fn part2(instructions: &Self::Parsed) -> Answer {
+        let history = run_program(instructions);
+        const SCREEN_WIDTH: usize = 40;
+        let screen = history[1..]
+            .chunks(SCREEN_WIDTH)
+            .map(|chunk| {
+                chunk
+                    .iter()
+                    .enumerate()
+                    .map(|(i, r)| {
+                        if ((r.value - 1) <= i as i64) && ((r.value + 1) >= i as i64) {
+                            '#'
+                        } else {
+                            '.'
+                        }
+                    })
+                    .collect::<String>()
+                    + "\n"
+            })
+            .collect::<String>();
+        Answer::Art(screen)
+    }
+}
+
+impl Generate for CathodeRayTube {
+    // At least 20 instructions, enough cycles for the first signal strength.
+    // X stays on the screen.
+    fn generate(rng: &mut Rng, size: usize) -> Self::Parsed {
+        let mut x = 1;
+        (0..size.max(20))
+            .map(|_| match rng.chance(40) {
+                true => Instruction {
+                    instruction: Instr::Noop,
+                    argument: 0,
+                },
+                false => {
+                    let argument = rng.signed(1 - x..40 - x);
+                    x += argument;
+                    Instruction {
+                        instruction: Instr::Addx,
+                        argument,
+                    }
+                }
+            })
+            .collect()
+    }
+    fn render(instructions: &Self::Parsed) -> String {
+        instructions
+            .iter()
+            .map(|i| match i.instruction {
+                Instr::Addx => format!("addx {}", i.argument),
+                Instr::Noop => "noop".to_string(),
+            })
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+}
+
+pub const DAY: Day = Day::new::<CathodeRayTube>(10, "Cathode-Ray Tube");
@@ -0,0 +1,318 @@
+// Advent Of Code
+// https://adventofcode.com/2022/day/11
+//
+// --- Day 11: Monkey in the Middle ---
+// Puzzle description: puzzles/day_11.md
+
+use crate::days::Day;
+use crate::generate::{Generate, Rng};
+use crate::parse::{self, Line, ParseError};
+use crate::trace;
+use crate::{Answer, Solution};
+
+type Troop = Vec<Monkey>;
+
+#[allow(dead_code)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Monkey {
+    monkey: u128,
+    items: Vec<u128>,
+    inspection: Inspection,
+    test: MonkeyTest,
+    inspection_counter: u128,
+    worry_divisor: u128,
+}
+
+impl Monkey {
+    fn apply_op(&mut self, value: u128, supermodulo: u128) -> u128 {
+        self.inspection_counter += 1;
+        (self.inspection.apply(value) % supermodulo) / self.worry_divisor
+    }
+    fn apply_test(&self, value: u128) -> u128 {
+        self.test.apply(value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Inspection {
+    operator: char,
+    operand: u128,
+    self_operand: bool,
+}
+impl Inspection {
+    fn apply(&self, operand2: u128) -> u128 {
+        let operand1 = if self.self_operand {
+            operand2
+        } else {
+            self.operand
+        };
+        match self.operator {
+            '+' => operand1 + operand2,
+            '*' => operand1 * operand2,
+            o => panic!("Operator {o} not found"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct MonkeyTest {
+    divisible: u128,
+    monkey_true: u128,
+    monkey_false: u128,
+}
+impl MonkeyTest {
+    fn apply(&self, value: u128) -> u128 {
+        match value % self.divisible {
+            0 => self.monkey_true,
+            _ => self.monkey_false,
+        }
+    }
+}
+
+fn parse_input(input_text: &str) -> Result<Troop, ParseError> {
+    fn parse_monkey_op(op: Line) -> Result<Inspection, ParseError> {
+        let (operator, val) = op.trim().split_once(" ")?;
+        let operator = match operator.text() {
+            "+" => '+',
+            "*" => '*',
+            _ => return Err(operator.error("`+` or `*`")),
+        };
+        let self_operand = val.text() == "old";
+        let operand = if self_operand { 0 } else { val.parse()? };
+        Ok(Inspection {
+            operator,
+            operand,
+            self_operand,
+        })
+    }
+
+    fn parse_monkey(block: &[Line]) -> Result<Monkey, ParseError> {
+        let [header, items, operation, test, if_true, if_false] = block else {
+            return Err(block[0].error("six lines describing a monkey"));
+        };
+        let monkey = header.trim().strip_prefix("Monkey ")?.int()?;
+        let items = items
+            .after("Starting items:")?
+            .split(",")
+            .map(|item| item.parse())
+            .collect::<Result<Vec<u128>, ParseError>>()?;
+        let inspection = parse_monkey_op(operation.after("Operation: new = old")?)?;
+        let divisor = test.after("Test: divisible by")?.trim();
+        let test = MonkeyTest {
+            divisible: test.field("Test: divisible by")?,
+            monkey_true: if_true.field("If true: throw to monkey")?,
+            monkey_false: if_false.field("If false: throw to monkey")?,
+        };
+        if test.divisible == 0 {
+            return Err(divisor.error("a divisor above 0"));
+        }
+        Ok(Monkey {
+            monkey,
+            items,
+            inspection,
+            test,
+            inspection_counter: 0,
+            worry_divisor: 1,
+        })
+    }
+
+    let blocks = parse::blocks(input_text);
+    let troop = blocks
+        .iter()
+        .map(|block| parse_monkey(block))
+        .collect::<Result<Troop, ParseError>>()?;
+    // Monkeys throw to each other by their place in the troop.
+    for (monkey, block) in troop.iter().zip(&blocks) {
+        let targets = [
+            (monkey.test.monkey_true, block[4]),
+            (monkey.test.monkey_false, block[5]),
+        ];
+        for (target, line) in targets {
+            if target >= troop.len() as u128 {
+                let target = line.after("throw to monkey")?.trim();
+                return Err(target.error(format!("a monkey from 0 to {}", troop.len() - 1)));
+            }
+        }
+    }
+    Ok(troop)
+}
+
+fn round(troop: &mut Troop) {
+    let supermodulo = troop
+        .iter()
+        .map(|monkey| monkey.test.divisible)
+        .product::<u128>();
+    for i in 0..troop.len() {
+        let throw_items: Vec<(u128, usize)>;
+        {
+            let turn_monkey = &mut troop[i];
+            throw_items = turn_monkey
+                .items
+                .clone()
+                .iter()
+                .map(|item| {
+                    let worry = turn_monkey.apply_op(*item, supermodulo);
+                    let throw_idx = turn_monkey.apply_test(worry) as usize;
+                    (worry, throw_idx)
+                })
+                .collect::<Vec<_>>();
+            turn_monkey.items.clear();
+        }
+        {
+            throw_items.iter().for_each(|(worry, throw_idx)| {
+                troop[*throw_idx].items.push(*worry);
+            })
+        }
+    }
+}
+
+fn trace_troop(troop: &Troop) {
+    troop.iter().for_each(|m| {
+        let index = m.monkey;
+        let items = &m.items;
+        let inspected = &m.inspection_counter;
+        trace!(
+            Trace,
+            "Monkey {index} Inspected {inspected} items. Current items: {items:?}"
+        );
+    })
+}
+
+fn monkey_business(troop: &Troop, n_rounds: usize, worry_divisor: u128) -> u64 {
+    let mut troop = troop
+        .iter()
+        .cloned()
+        .map(|monkey| Monkey {
+            worry_divisor,
+            ..monkey
+        })
+        .collect::<Troop>();
+    for _ in 0..n_rounds {
+        round(&mut troop);
+        trace_troop(&troop);
+    }
+    let top_inspected = troop
+        .iter()
+        .map(|m| m.inspection_counter)
+        .fold((0, 0), |acc, item| {
+            if item >= acc.0 {
+                (item, acc.0)
+            } else if item > acc.1 {
+                (acc.0, item)
+            } else {
+                acc
+            }
+        });
+    (top_inspected.0 * top_inspected.1) as u64
+}
+
+struct MonkeyInTheMiddle;
+impl Solution for MonkeyInTheMiddle {
+    type Parsed = Troop;
+
+    fn parse(input_text: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input_text)
+    }
+    fn part1(troop: &Self::Parsed) -> Answer {
+        const WORRY_DIVISOR: u128 = 3;
+        const N_ROUNDS: usize = 20;
+        monkey_business(troop, N_ROUNDS, WORRY_DIVISOR).into()
+    }
+    fn part2(troop: &Self::Parsed) -> Answer {
+        const WORRY_DIVISOR: u128 = 1;
+        const N_ROUNDS: usize = 10000;
+        monkey_business(troop, N_ROUNDS, WORRY_DIVISOR).into()
+    }
+}
+
+impl Generate for MonkeyInTheMiddle {
+    // Each monkey tests for a different prime, like the puzzle's, so worry
+    // levels stay bounded in part two.
+    fn generate(rng: &mut Rng, size: usize) -> Self::Parsed {
+        let mut primes = vec![2, 3, 5, 7, 11, 13, 17, 19, 23];
+        rng.shuffle(&mut primes);
+        let monkeys = size.clamp(2, primes.len());
+        (0..monkeys)
+            .map(|monkey| {
+                let others = (0..monkeys)
+                    .filter(|other| *other != monkey)
+                    .collect::<Vec<usize>>();
+                let inspection = match rng.range(0..3) {
+                    0 => Inspection {
+                        operator: '+',
+                        operand: rng.range(1..9) as u128,
+                        self_operand: false,
+                    },
+                    1 => Inspection {
+                        operator: '*',
+                        operand: rng.range(2..20) as u128,
+                        self_operand: false,
+                    },
+                    _ => Inspection {
+                        operator: '*',
+                        operand: 0,
+                        self_operand: true,
+                    },
+                };
+                Monkey {
+                    monkey: monkey as u128,
+                    items: (0..rng.range(1..6))
+                        .map(|_| rng.range(50..100) as u128)
+                        .collect(),
+                    inspection,
+                    test: MonkeyTest {
+                        divisible: primes[monkey],
+                        monkey_true: *rng.pick(&others) as u128,
+                        monkey_false: *rng.pick(&others) as u128,
+                    },
+                    inspection_counter: 0,
+                    worry_divisor: 1,
+                }
+            })
+            .collect()
+    }
+    fn render(troop: &Self::Parsed) -> String {
+        troop
+            .iter()
+            .map(|m| {
+                let items = m
+                    .items
+                    .iter()
+                    .map(|item| item.to_string())
+                    .collect::<Vec<String>>();
+                let operand = match m.inspection.self_operand {
+                    true => "old".to_string(),
+                    false => m.inspection.operand.to_string(),
+                };
+                format!(
+                    "Monkey {}:\n  Starting items: {}\n  Operation: new = old {} {}\n  Test: divisible by {}\n    If true: throw to monkey {}\n    If false: throw to monkey {}",
+                    m.monkey,
+                    items.join(", "),
+                    m.inspection.operator,
+                    operand,
+                    m.test.divisible,
+                    m.test.monkey_true,
+                    m.test.monkey_false
+                )
+            })
+            .collect::<Vec<String>>()
+            .join("\n\n")
+    }
+}
+
+pub const DAY: Day = Day::new::<MonkeyInTheMiddle>(11, "Monkey in the Middle");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::examples::load_example;
+
+    #[test]
+    fn rejects_throws_to_missing_monkeys() {
+        let example = load_example(11, 1).unwrap();
+        assert!(parse_input(&example).is_ok());
+        let last_monkey = example.replace("throw to monkey 3", "throw to monkey 4");
+        let err = parse_input(&last_monkey).unwrap_err().to_string();
+        assert!(err.contains("a monkey from 0 to 3"), "{}", err);
+    }
+}
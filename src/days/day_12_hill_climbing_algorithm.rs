@@ -0,0 +1,191 @@
+// Advent Of Code
+// https://adventofcode.com/2022/day/12
+//
+// --- Day 12: Hill Climbing Algorithm ---
+// Puzzle description: puzzles/day_12.md
+
+use crate::days::Day;
+use crate::generate::{Generate, Rng};
+use crate::geom::{Direction4, Point2};
+use crate::grid::Grid;
+use crate::parse::{self, ParseError};
+use crate::search::{bfs_multi, Graph, Path};
+use crate::trace;
+use crate::{Answer, Solution};
+
+type Coord = Point2<usize>;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Board {
+    board: Grid<u8>,
+    start_marker: u8,
+    end_marker: u8,
+    lowest: u8,
+    highest: u8,
+    max_step: u8,
+}
+
+impl Board {
+    fn new(board: Grid<u8>, start_marker: u8, end_marker: u8) -> Self {
+        let lowest = b'a';
+        let highest = b'z';
+        let max_step: u8 = 1;
+
+        Self {
+            board,
+            start_marker,
+            end_marker,
+            lowest,
+            highest,
+            max_step,
+        }
+    }
+    fn find_u8(&self, c: u8) -> Vec<Coord> {
+        self.board
+            .iter()
+            .filter(|(_, _c)| c == **_c)
+            .map(|((x, y), _)| Coord { y, x })
+            .collect()
+    }
+
+    fn get_height(&self, y: usize, x: usize) -> u8 {
+        match self.board[(x, y)] {
+            m if m == self.start_marker => self.lowest,
+            m if m == self.end_marker => self.highest,
+            m => m,
+        }
+    }
+
+    fn possible_coords(&self, position: &Coord) -> Vec<Coord> {
+        let Coord { x, y } = *position;
+        let max_height = self.get_height(y, x) + self.max_step;
+        self.board
+            .neighbours4(x, y)
+            .filter(|&(new_x, new_y)| self.get_height(new_y, new_x) <= max_height)
+            .map(|(x, y)| Coord { x, y })
+            .collect()
+    }
+
+    // Shortest climb from any of `starts` to the end marker.
+    fn solve(&self, starts: Vec<Coord>) -> Option<Path<Coord>> {
+        let end = self.find_u8(self.end_marker)[0];
+        bfs_multi(self, starts, |position| *position == end)
+    }
+
+    fn draw_moves(&self, moves: &[Coord]) -> Grid<char> {
+        let mut board = Grid::new(self.board.width(), self.board.height(), '.');
+
+        fn find_direction(from: &Coord, to: &Coord) -> char {
+            Direction4::ALL
+                .into_iter()
+                .find(|d| from.step(*d) == Some(*to))
+                .expect("Can't find direction")
+                .arrow()
+        }
+        moves
+            .windows(2)
+            .for_each(|step| board[step[0]] = find_direction(&step[0], &step[1]));
+        board
+    }
+}
+impl Graph for Board {
+    type Node = Coord;
+
+    fn neighbours(&self, position: &Coord) -> impl IntoIterator<Item = (Coord, usize)> {
+        self.possible_coords(position).into_iter().map(|c| (c, 1))
+    }
+}
+
+fn parse_input(input_text: &str, start_marker: u8, end_marker: u8) -> Result<Board, ParseError> {
+    let board = parse::grid(input_text, "a height from a to z, S or E", |c| {
+        let c = u8::try_from(c).ok()?;
+        (c.is_ascii_lowercase() || c == start_marker || c == end_marker).then_some(c)
+    })?;
+    let board = Board::new(board, start_marker, end_marker);
+    for marker in [start_marker, end_marker] {
+        if board.find_u8(marker).is_empty() {
+            let expected = format!("a `{}` somewhere on the map", marker as char);
+            return Err(parse::first_line(input_text).error(expected));
+        }
+    }
+    Ok(board)
+}
+
+const START_MARKER: u8 = b'S';
+const END_MARKER: u8 = b'E';
+
+struct HillClimbingAlgorithm;
+impl Solution for HillClimbingAlgorithm {
+    type Parsed = Board;
+
+    fn parse(input_text: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input_text, START_MARKER, END_MARKER)
+    }
+    fn part1(board: &Self::Parsed) -> Answer {
+        let solution = board.solve(board.find_u8(START_MARKER)).unwrap();
+        trace!(
+            Debug,
+            "Climb from S:\n{}",
+            board.draw_moves(&solution.nodes)
+        );
+        solution.cost.into()
+    }
+    fn part2(board: &Self::Parsed) -> Answer {
+        // Searching from every lowest square at once finds the closest one.
+        const CANDIDATE_HEIGHT: u8 = b'a';
+        let mut start_candidates = board.find_u8(CANDIDATE_HEIGHT);
+        start_candidates.extend(board.find_u8(START_MARKER));
+        let solution = board.solve(start_candidates).unwrap();
+        trace!(
+            Debug,
+            "Climb from the closest a:\n{}",
+            board.draw_moves(&solution.nodes)
+        );
+        solution.cost.into()
+    }
+}
+
+impl Generate for HillClimbingAlgorithm {
+    // Hills that are never steeper than one step, so any square can climb to
+    // the top. The start is on the lowest ground, at least 25 squares away
+    // from the end at the top.
+    fn generate(rng: &mut Rng, size: usize) -> Self::Parsed {
+        let (width, height) = ((size * 2).max(26), size.max(5));
+        let start = Coord {
+            x: 0,
+            y: rng.range(0..height),
+        };
+        let end = Coord {
+            x: rng.range(25..width),
+            y: rng.range(0..height),
+        };
+        let mut peaks = (0..size.div_ceil(4).min(50))
+            .map(|_| {
+                let peak = Coord {
+                    x: rng.range(0..width),
+                    y: rng.range(0..height),
+                };
+                (peak, rng.range(0..25))
+            })
+            .collect::<Vec<(Coord, usize)>>();
+        peaks.push((end, 25));
+        let mut board = Grid::new(width, height, b'a');
+        for (x, y) in board.positions().collect::<Vec<_>>() {
+            let c = Coord { x, y };
+            let hill = peaks
+                .iter()
+                .map(|(peak, top)| top.saturating_sub(peak.manhattan(&c)))
+                .max()
+                .unwrap();
+            board[c] = b'a' + hill.min(c.manhattan(&start)) as u8;
+        }
+        board[start] = START_MARKER;
+        board[end] = END_MARKER;
+        Board::new(board, START_MARKER, END_MARKER)
+    }
+    fn render(board: &Self::Parsed) -> String {
+        board.board.render(|c| *c as char)
+    }
+}
+
+pub const DAY: Day = Day::new::<HillClimbingAlgorithm>(12, "Hill Climbing Algorithm");
@@ -0,0 +1,258 @@
+// Advent Of Code
+// https://adventofcode.com/2022/day/13
+//
+// --- Day 13: Distress Signal ---
+// Puzzle description: puzzles/day_13.md
+
+use crate::days::Day;
+use crate::generate::{Generate, Rng};
+use crate::parse::{self, Line, ParseError};
+use crate::{Answer, Solution};
+use std::cmp::Ordering;
+
+#[derive(Debug, PartialEq, Clone)]
+enum Token {
+    Integer(usize),
+    List(TokenList),
+}
+impl Token {
+    fn recursive_tokens(&self) -> TokenList {
+        let mut tokens = vec![];
+        match self {
+            Token::Integer(integer) => tokens.push(Token::Integer(*integer)),
+            Token::List(list) => {
+                tokens.push(Token::List(list.clone()));
+                for token in list {
+                    tokens.extend(token.recursive_tokens())
+                }
+            }
+        };
+        tokens
+    }
+    fn parse_line(line: Line) -> Result<Token, ParseError> {
+        let (packet, rest) = Self::parse_packet(line)?;
+        if !rest.is_empty() {
+            return Err(rest.error("end of packet"));
+        }
+        Ok(Token::List(vec![packet]))
+    }
+    // A packet at the start of `line` and whatever follows it.
+    fn parse_packet(line: Line) -> Result<(Token, Line), ParseError> {
+        let Ok(mut rest) = line.strip_prefix("[") else {
+            let (integer, rest) = line.take_int()?;
+            return Ok((Token::Integer(integer), rest));
+        };
+        let mut tokens = vec![];
+        if let Ok(rest) = rest.strip_prefix("]") {
+            return Ok((Token::List(tokens), rest));
+        }
+        loop {
+            let (token, after) = Self::parse_packet(rest)?;
+            tokens.push(token);
+            match after.strip_prefix(",") {
+                Ok(next) => rest = next,
+                Err(_) => {
+                    let rest = after.strip_prefix("]")?;
+                    return Ok((Token::List(tokens), rest));
+                }
+            }
+        }
+    }
+}
+
+type TokenList = Vec<Token>;
+
+#[derive(Debug, PartialEq)]
+struct Packets {
+    left: Token,
+    right: Token,
+}
+
+fn parse_input(input_text: &str) -> Result<Vec<Packets>, ParseError> {
+    parse::blocks(input_text)
+        .into_iter()
+        .map(|block| match block[..] {
+            [left, right] => Ok(Packets {
+                left: Token::parse_line(left)?,
+                right: Token::parse_line(right)?,
+            }),
+            _ => Err(block[0].error("a pair of packets")),
+        })
+        .collect()
+}
+
+fn check_order(left: &TokenList, right: &TokenList) -> Option<bool> {
+    let l_len = left.len();
+    let r_len = right.len();
+
+    let mut i = 0;
+    loop {
+        if (i == r_len) && (i == l_len) {
+            break None;
+        } else if i >= l_len {
+            // If the left list runs out of items first, the inputs are in the right order.
+            break Some(true);
+        } else if i >= r_len {
+            // If the right list runs out of items first, the inputs are not in the right order.
+            break Some(false);
+        }
+        match (&left[i], &right[i]) {
+            //  - If both values are integers, the lower integer should come first.
+            (Token::Integer(il), Token::Integer(ir)) => match il.cmp(ir) {
+                //  If the left integer is lower than the right integer, the inputs are in the right order.
+                Ordering::Greater => break Some(false),
+                //  If the left integer is higher than the right integer, the inputs are not in the right order.
+                Ordering::Less => break Some(true),
+                //  Otherwise, the inputs are the same integer; continue checking the next part of the input.
+                Ordering::Equal => (),
+            },
+            // - If both values are lists, compare the first value of each list, then the second value, and so on.
+            (Token::List(ll), Token::List(lr)) => {
+                // If the lists are the same length and no comparison makes a decision about the order,
+                // continue checking the next part of the input.
+                if let Some(value) = check_order(ll, lr) {
+                    break Some(value);
+                }
+            }
+            // - If exactly one value is an integer, convert the integer to a list which
+            // contains that integer as its only value, then retry the comparison.
+            // For example, if comparing [0,0,0] and 2, convert the right value to [2] (a list containing 2);
+            // the result is then found by instead comparing [0,0,0] and [2].
+            (Token::Integer(il), Token::List(lr)) => {
+                if let Some(value) = check_order(&vec![Token::Integer(*il)], lr) {
+                    break Some(value);
+                }
+            }
+            (Token::List(ll), Token::Integer(ir)) => {
+                if let Some(value) = check_order(ll, &vec![Token::Integer(*ir)]) {
+                    break Some(value);
+                }
+            }
+        }
+        i += 1;
+    }
+}
+
+struct DistressSignal;
+impl Solution for DistressSignal {
+    type Parsed = Vec<Packets>;
+
+    fn parse(input_text: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input_text)
+    }
+    fn part1(packets: &Self::Parsed) -> Answer {
+        let right_order = packets
+            .iter()
+            .map(|packets| {
+                let Packets { left, right } = packets;
+                let left = left.recursive_tokens();
+                let right = right.recursive_tokens();
+                check_order(&left, &right).unwrap()
+            })
+            .collect::<Vec<bool>>();
+        right_order
+            .iter()
+            .enumerate()
+            .fold(0, |acc, (i, order)| if *order { acc + i + 1 } else { acc })
+            .into()
+    }
+    fn part2(packets: &Self::Parsed) -> Answer {
+        let mut packets = packets
+            .iter()
+            .flat_map(|Packets { left, right }| [left.clone(), right.clone()])
+            .collect::<Vec<Token>>();
+        let divivder_2: Token = Token::List(vec![Token::List(vec![Token::Integer(2)])]);
+        let divivder_6: Token = Token::List(vec![Token::List(vec![Token::Integer(6)])]);
+        packets.push(divivder_2.clone());
+        packets.push(divivder_6.clone());
+        packets.sort_by(|left, right| {
+            let left = left.recursive_tokens();
+            let right = right.recursive_tokens();
+            if check_order(&left, &right).unwrap() {
+                Ordering::Less
+            } else {
+                Ordering::Greater
+            }
+        });
+        let position_2 = packets
+            .iter()
+            .position(|packet| *packet == divivder_2)
+            .unwrap()
+            + 1;
+        let position_6 = packets
+            .iter()
+            .position(|packet| *packet == divivder_6)
+            .unwrap()
+            + 1;
+        (position_2 * position_6).into()
+    }
+}
+
+fn random_packet(rng: &mut Rng, depth: usize) -> Token {
+    let items = (0..rng.range(0..5))
+        .map(|_| match depth > 0 && rng.chance(30) {
+            true => random_packet(rng, depth - 1),
+            false => Token::Integer(rng.range(0..11)),
+        })
+        .collect();
+    Token::List(items)
+}
+
+fn render_packet(token: &Token) -> String {
+    match token {
+        Token::Integer(integer) => integer.to_string(),
+        Token::List(list) => {
+            let items = list.iter().map(render_packet).collect::<Vec<String>>();
+            format!("[{}]", items.join(","))
+        }
+    }
+}
+
+impl Generate for DistressSignal {
+    // Packets that never compare as equal to each other or to the divider
+    // packets, or their order would be undecided.
+    fn generate(rng: &mut Rng, size: usize) -> Self::Parsed {
+        let mut lines = [2, 6]
+            .map(|divider| Token::List(vec![Token::List(vec![Token::Integer(divider)])]))
+            .to_vec();
+        // Kept in order, an equal packet is found by binary search.
+        let mut sorted = lines
+            .iter()
+            .map(|line| line.recursive_tokens())
+            .collect::<Vec<TokenList>>();
+        while lines.len() < 2 + size * 2 {
+            let line = Token::List(vec![random_packet(rng, 3)]);
+            let tokens = line.recursive_tokens();
+            let found = sorted.binary_search_by(|other| match check_order(other, &tokens) {
+                Some(true) => Ordering::Less,
+                Some(false) => Ordering::Greater,
+                None => Ordering::Equal,
+            });
+            if let Err(at) = found {
+                sorted.insert(at, tokens);
+                lines.push(line);
+            }
+        }
+        lines[2..]
+            .chunks(2)
+            .map(|pair| Packets {
+                left: pair[0].clone(),
+                right: pair[1].clone(),
+            })
+            .collect()
+    }
+    // Parsed lines are wrapped in a list, see `Token::parse_line`.
+    fn render(packets: &Self::Parsed) -> String {
+        let line = |token: &Token| match token {
+            Token::List(list) => list.iter().map(render_packet).collect::<String>(),
+            integer => render_packet(integer),
+        };
+        packets
+            .iter()
+            .map(|Packets { left, right }| format!("{}\n{}", line(left), line(right)))
+            .collect::<Vec<String>>()
+            .join("\n\n")
+    }
+}
+
+pub const DAY: Day = Day::new::<DistressSignal>(13, "Distress Signal");
@@ -0,0 +1,276 @@
+// Advent Of Code
+// https://adventofcode.com/2022/day/14
+//
+// --- Day 14: Regolith Reservoir ---
+// Puzzle description: puzzles/day_14.md
+
+use crate::days::Day;
+use crate::generate::{Generate, Rng};
+use crate::geom::Point2;
+use crate::grid::Grid;
+use crate::parse::{self, Line, ParseError};
+use crate::{Answer, Solution};
+use std::fmt;
+
+type Coord = Point2<usize>;
+
+// The cave is stored densely, so it can't be arbitrarily large.
+const MAX_COORD: usize = 1000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Tile {
+    Air,
+    Wall,
+    Sand,
+}
+impl fmt::Display for Tile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Tile::Air => write!(f, "."),
+            Tile::Wall => write!(f, "#"),
+            Tile::Sand => write!(f, "o"),
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Eq)]
+struct Board {
+    x_min: usize,
+    x_max: usize,
+    tiles: Grid<Tile>,
+}
+impl fmt::Debug for Board {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let board_str = self
+            .tiles
+            .rows()
+            .map(|row| {
+                row[self.x_min - 5..=self.x_max + 8]
+                    .iter()
+                    .map(|tile| tile.to_string())
+                    .collect::<String>()
+                    + "\n"
+            })
+            .collect::<String>();
+        write!(f, "\n{}", board_str)
+    }
+}
+impl Board {
+    fn new(width: usize, height: usize, x_min: usize, x_max: usize) -> Self {
+        let mut tiles = Grid::new(width, height, Tile::Air);
+        tiles.row_mut(height - 1).fill(Tile::Wall);
+        Board {
+            x_min,
+            x_max,
+            tiles,
+        }
+    }
+    fn parse_path(line: Line) -> Result<Vec<Coord>, ParseError> {
+        let mut path: Vec<Coord> = vec![];
+        for item in line.split(" -> ") {
+            let (x, y) = item.trim().split_once(",")?;
+            let coord = Coord {
+                x: x.parse()?,
+                y: y.parse()?,
+            };
+            if coord.x > MAX_COORD || coord.y > MAX_COORD {
+                return Err(item.error(format!("coordinates up to {}", MAX_COORD)));
+            }
+            if let Some(prev) = path.last() {
+                if prev.x != coord.x && prev.y != coord.y {
+                    return Err(item.error("a horizontal or vertical line"));
+                }
+            }
+            path.push(coord);
+        }
+        Ok(path)
+    }
+    fn from_str(input_text: &str) -> Result<Self, ParseError> {
+        let wall_coords = parse::lines(input_text.trim_end())
+            .map(Self::parse_path)
+            .collect::<Result<Vec<Vec<Coord>>, ParseError>>()?;
+        if wall_coords.is_empty() {
+            return Err(parse::first_line(input_text).error("paths of rock"));
+        }
+        Ok(Self::from_paths(&wall_coords))
+    }
+    fn from_paths(wall_coords: &[Vec<Coord>]) -> Self {
+        let x_min = wall_coords
+            .iter()
+            .map(|line| line.iter().map(|c| c.x).min().unwrap())
+            .min()
+            .unwrap();
+        let x_max = wall_coords
+            .iter()
+            .map(|line| line.iter().map(|c| c.x).max().unwrap())
+            .max()
+            .unwrap();
+        let y_max = wall_coords
+            .iter()
+            .map(|line| line.iter().map(|c| c.y).max().unwrap())
+            .max()
+            .unwrap();
+        // Room for the sand to pile up on the floor, and for walls at x = 0.
+        let width = (x_min + x_max).max(x_max + 1);
+        let mut board = Self::new(width, y_max + 3, x_min, x_max);
+        wall_coords.iter().for_each(|line| {
+            line.iter()
+                .zip(line[1..].iter())
+                .flat_map(|(prev, cur)| {
+                    let dx = prev.x.abs_diff(cur.x);
+                    let dy = prev.y.abs_diff(cur.y);
+                    match (dx, dy) {
+                        (0, _) => {
+                            let x = prev.x;
+                            let y_min = if prev.y < cur.y { prev.y } else { cur.y };
+                            let y_max = if prev.y > cur.y { prev.y } else { cur.y };
+                            (y_min..=y_max)
+                                .map(|y| Coord { x, y })
+                                .collect::<Vec<Coord>>()
+                        }
+                        (_, 0) => {
+                            let y = prev.y;
+                            let x_min = if prev.x < cur.x { prev.x } else { cur.x };
+                            let x_max = if prev.x > cur.x { prev.x } else { cur.x };
+                            (x_min..=x_max)
+                                .map(|x| Coord { x, y })
+                                .collect::<Vec<Coord>>()
+                        }
+                        _ => unreachable!("Diagonal paths are rejected while parsing"),
+                    }
+                })
+                .for_each(|c| {
+                    board.tiles[c] = Tile::Wall;
+                });
+        });
+        board
+    }
+
+    fn is_free(&self, x: usize, y: usize) -> Option<bool> {
+        self.tiles.get(x, y).map(|tile| *tile == Tile::Air)
+    }
+    fn drop_sand(&mut self, x: usize, y: usize) -> Result<Coord, &str> {
+        let mut x = x;
+        let mut y = y;
+        let sand_pos = loop {
+            // 1. Check bellow
+            match self.is_free(x, y + 1) {
+                Some(true) => {
+                    y += 1;
+                    continue;
+                }
+                Some(false) => (),
+                None => return Err("Falling out of bounds"),
+            };
+            // 2. Check lower left diagonal
+            if let Some(true) = self.is_free(x - 1, y + 1) {
+                x -= 1;
+                y += 1;
+                continue;
+            };
+            // 3. Check lower right diagonal
+            if let Some(true) = self.is_free(x + 1, y + 1) {
+                x += 1;
+                y += 1;
+                continue;
+            };
+            // 4. Otherwise stop
+            break Coord { x, y };
+        };
+        self.tiles[sand_pos] = Tile::Sand;
+        Ok(sand_pos)
+    }
+
+    fn off_bounds(&self, x: usize, y: usize) -> bool {
+        (x < self.x_min) || (x > self.x_max) || (y == self.tiles.height() - 2)
+    }
+
+    fn drop_sand_until_fall(&mut self, x: usize, y: usize) -> usize {
+        let mut i = 0;
+        loop {
+            let sand = self.drop_sand(x, y).expect("Sand dropped");
+            if self.off_bounds(sand.x, sand.y) {
+                return i;
+            }
+            i += 1;
+        }
+    }
+
+    fn drop_sand_until_full(&mut self, x: usize, y: usize) -> usize {
+        let mut i = 0;
+        loop {
+            let sand = self.drop_sand(x, y).expect("Sand dropped");
+            if sand.x == x && sand.y == y {
+                return i + 1;
+            }
+            i += 1;
+        }
+    }
+}
+
+const SAND_X: usize = 500;
+const SAND_Y: usize = 0;
+
+struct RegolithReservoir;
+impl Solution for RegolithReservoir {
+    type Parsed = Board;
+
+    fn parse(input_text: &str) -> Result<Self::Parsed, ParseError> {
+        Board::from_str(input_text)
+    }
+    fn part1(board: &Self::Parsed) -> Answer {
+        let mut board = board.clone();
+        board.drop_sand_until_fall(SAND_X, SAND_Y).into()
+    }
+    fn part2(board: &Self::Parsed) -> Answer {
+        let mut board = board.clone();
+        board.drop_sand_until_full(SAND_X, SAND_Y).into()
+    }
+}
+
+impl Generate for RegolithReservoir {
+    // Paths of rock below the sand source, turning a few times each. The
+    // first one starts right below it.
+    fn generate(rng: &mut Rng, size: usize) -> Self::Parsed {
+        let paths = (0..size)
+            .map(|i| {
+                let mut at = Coord {
+                    x: if i == 0 { SAND_X } else { rng.range(460..540) },
+                    y: rng.range(13..170),
+                };
+                let mut path = vec![at];
+                for turn in 0..rng.range(1..5) {
+                    if turn % 2 == 0 {
+                        at.x = rng.range(at.x - 8..at.x + 9);
+                    } else {
+                        at.y = rng.range(at.y.saturating_sub(8).max(1)..at.y + 9);
+                    }
+                    path.push(at);
+                }
+                path
+            })
+            .collect::<Vec<Vec<Coord>>>();
+        Board::from_paths(&paths)
+    }
+    // The rock as one path per horizontal run, above the floor.
+    fn render(board: &Self::Parsed) -> String {
+        let floor = board.tiles.height() - 1;
+        let mut paths = vec![];
+        for (y, row) in board.tiles.rows().take(floor).enumerate() {
+            let mut x = 0;
+            while x < row.len() {
+                let start = x;
+                while x < row.len() && row[x] == Tile::Wall {
+                    x += 1;
+                }
+                if x > start {
+                    paths.push(format!("{},{} -> {},{}", start, y, x - 1, y));
+                }
+                x += 1;
+            }
+        }
+        paths.join("\n")
+    }
+}
+
+pub const DAY: Day = Day::new::<RegolithReservoir>(14, "Regolith Reservoir");
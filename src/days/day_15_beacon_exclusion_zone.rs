@@ -0,0 +1,362 @@
+// Advent Of Code
+// https://adventofcode.com/2022/day/15
+//
+// --- Day 15: Beacon Exclusion Zone ---
+// Puzzle description: puzzles/day_15.md
+
+use crate::days::Day;
+use crate::generate::{Generate, Rng};
+use crate::geom::Point2;
+use crate::parse::{self, ParseError};
+use crate::reference::Reference;
+use crate::{Answer, Solution};
+use std::collections::BTreeSet;
+use std::fmt;
+
+type Coord = Point2<isize>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Scan {
+    beacon: Coord,
+    sensor: Coord,
+    distance: isize,
+}
+impl Scan {
+    fn new(sensor: Coord, beacon: Coord) -> Self {
+        Self {
+            sensor,
+            beacon,
+            distance: sensor.manhattan(&beacon),
+        }
+    }
+    fn in_range(&self, coord: &Coord) -> bool {
+        self.sensor.manhattan(coord) <= self.distance
+    }
+    fn is_beacon(&self, coord: &Coord) -> bool {
+        self.beacon == *coord
+    }
+    fn is_sensor(&self, coord: &Coord) -> bool {
+        self.sensor == *coord
+    }
+    // Whether both can be in a report: each beacon is the closest one to its
+    // sensor, without ties.
+    fn agrees_with(&self, other: &Scan) -> bool {
+        self.sensor != other.sensor
+            && (self.beacon == other.beacon
+                || !(self.in_range(&other.beacon) || other.in_range(&self.beacon)))
+    }
+    // The columns of row `y` in range, if any.
+    fn row_range(&self, y: isize) -> Option<(isize, isize)> {
+        let width = self.distance - (self.sensor.y - y).abs();
+        (width >= 0).then_some((self.sensor.x - width, self.sensor.x + width))
+    }
+}
+
+#[derive(PartialEq, Eq)]
+struct Zone {
+    scans: Vec<Scan>,
+    x_min: isize,
+    x_max: isize,
+    y_min: isize,
+    y_max: isize,
+}
+impl fmt::Debug for Zone {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let zone_str = (self.y_min..self.y_max)
+            .map(|y| {
+                format!("{:03}", y.to_string())
+                    + &(self.x_min..=self.x_max)
+                        .map(|x| {
+                            let c = &Coord { x, y };
+                            self.scans.iter().fold('.', |acc, scan| {
+                                if acc != '.' && acc != '#' {
+                                    acc
+                                } else if scan.is_beacon(c) {
+                                    'B'
+                                } else if scan.is_sensor(c) {
+                                    'S'
+                                } else if scan.in_range(c) {
+                                    '#'
+                                } else {
+                                    acc
+                                }
+                            })
+                        })
+                        .collect::<String>()
+                    + "\n"
+            })
+            .collect::<String>();
+        write!(f, "\n{}", zone_str)
+    }
+}
+impl Zone {
+    fn from_string(input: &str) -> Result<Self, ParseError> {
+        let scans = parse::lines(input)
+            .map(|line| {
+                let (sensor, beacon) = line.split_once(":")?;
+                let sensor = Coord {
+                    x: sensor.field("x=")?,
+                    y: sensor.field("y=")?,
+                };
+                let beacon = Coord {
+                    x: beacon.field("x=")?,
+                    y: beacon.field("y=")?,
+                };
+                Ok(Scan::new(sensor, beacon))
+            })
+            .collect::<Result<Vec<Scan>, ParseError>>()?;
+        if scans.is_empty() {
+            return Err(parse::first_line(input).error("sensor reports"));
+        }
+        Ok(Self::from_scans(scans))
+    }
+    fn from_scans(scans: Vec<Scan>) -> Self {
+        let x_min = scans
+            .iter()
+            .map(|scan| scan.sensor.x - scan.distance)
+            .min()
+            .unwrap();
+        let x_max = scans
+            .iter()
+            .map(|scan| scan.sensor.x + scan.distance)
+            .max()
+            .unwrap();
+        let y_min = scans
+            .iter()
+            .map(|scan| scan.sensor.y - scan.distance)
+            .min()
+            .unwrap();
+        let y_max = scans
+            .iter()
+            .map(|scan| scan.sensor.y + scan.distance)
+            .max()
+            .unwrap();
+
+        Self {
+            scans,
+            x_min,
+            x_max,
+            y_min,
+            y_max,
+        }
+    }
+    fn not_beacon(&self, c: &Coord) -> bool {
+        self.scans.iter().any(|s| s.in_range(c) && !s.is_beacon(c))
+    }
+    fn not_beacon_row(&self, y: isize) -> isize {
+        (self.x_min..=self.x_max)
+            .map(|x| self.not_beacon(&Coord { x, y }) as isize)
+            .sum::<isize>()
+    }
+    fn is_empty(&self, c: &Coord) -> bool {
+        self.scans.iter().all(|s| !s.in_range(c))
+    }
+    fn distress_beacon(&self, low: isize, high: isize) -> Option<Coord> {
+        let candidates = self
+            .scans
+            .iter()
+            .flat_map(|scan| {
+                (0..=(scan.distance + 1))
+                    .flat_map(|i| {
+                        let j = (scan.distance + 1) - i;
+                        let Coord { x, y } = scan.sensor;
+                        vec![
+                            Coord { x: x + i, y: y + j },
+                            Coord { x: x + i, y: y - j },
+                            Coord { x: x - i, y: y - j },
+                            Coord { x: x - i, y: y + j },
+                        ]
+                    })
+                    .filter(|c| {
+                        c.x >= low && c.x <= high && c.y >= low && c.y <= high && self.is_empty(c)
+                    })
+                    .collect::<BTreeSet<Coord>>()
+            })
+            .collect::<BTreeSet<Coord>>();
+        candidates.iter().next().copied()
+    }
+}
+
+fn not_beacon_count(zone: &Zone, row: isize) -> u64 {
+    let y = row;
+    zone.not_beacon_row(y) as u64
+}
+
+fn tuning_frequency(zone: &Zone, low: isize, high: isize) -> Option<u64> {
+    let distress = zone.distress_beacon(low, high)?;
+    Some((X_MUL * distress.x + distress.y) as u64)
+}
+
+const X_MUL: isize = 4000000;
+const Y: isize = 2000000;
+const LOW: isize = 0;
+const HIGH: isize = 4000000;
+// When every spot of the square is in range of some sensor.
+const NO_DISTRESS_BEACON: &str = "no distress beacon";
+
+// Row `y` as sorted, merged ranges of columns in range of some sensor.
+fn reference_row(zone: &Zone, y: isize) -> Vec<(isize, isize)> {
+    let mut ranges = zone
+        .scans
+        .iter()
+        .filter_map(|scan| scan.row_range(y))
+        .collect::<Vec<(isize, isize)>>();
+    ranges.sort();
+    let mut merged: Vec<(isize, isize)> = vec![];
+    for (start, end) in ranges {
+        match merged.last_mut() {
+            Some(last) if start <= last.1 + 1 => last.1 = last.1.max(end),
+            _ => merged.push((start, end)),
+        }
+    }
+    merged
+}
+
+impl Reference for BeaconExclusionZone {
+    // Row `Y` in range, less the beacons on it.
+    fn reference_part1(zone: &Self::Parsed) -> Answer {
+        let row = reference_row(zone, Y);
+        let beacons = zone
+            .scans
+            .iter()
+            .map(|scan| scan.beacon)
+            .filter(|beacon| {
+                beacon.y == Y && row.iter().any(|(a, b)| (*a..=*b).contains(&beacon.x))
+            })
+            .collect::<BTreeSet<Coord>>();
+        let in_range = row.iter().map(|(a, b)| b - a + 1).sum::<isize>();
+        ((in_range - beacons.len() as isize) as u64).into()
+    }
+    // Every row of the square in turn, for a column no sensor reaches.
+    fn reference_part2(zone: &Self::Parsed) -> Answer {
+        for y in LOW..=HIGH {
+            let mut x = LOW;
+            for (start, end) in reference_row(zone, y) {
+                if start > x {
+                    break;
+                }
+                x = x.max(end + 1);
+            }
+            if x <= HIGH {
+                return ((X_MUL * x + y) as u64).into();
+            }
+        }
+        NO_DISTRESS_BEACON.into()
+    }
+}
+
+impl Generate for BeaconExclusionZone {
+    // The distress beacon boxed in by eight sensors that reach all of the
+    // square but it: four along the axes leave only the diagonals through
+    // it, four along the diagonals cover those. Their beacons lie on the
+    // outer edge of the box, `diagonal < axis < 2 * diagonal` sees to that.
+    // About `size` more sensors further out cover bits of row `Y`, some
+    // sitting right on it so that their reach ends on it too.
+    fn generate(rng: &mut Rng, size: usize) -> Self::Parsed {
+        let square = LOW as i64..HIGH as i64 + 1;
+        let hidden = Coord::new(
+            rng.signed(square.clone()) as isize,
+            rng.signed(square) as isize,
+        );
+        let reach = [
+            hidden.x - LOW,
+            HIGH - hidden.x,
+            hidden.y - LOW,
+            HIGH - hidden.y,
+        ]
+        .into_iter()
+        .max()
+        .unwrap();
+        let (diagonal, axis) = (reach + 2, reach + 3);
+        let mut scans = vec![];
+        for (dx, dy) in [(1, 0), (0, 1), (-1, 0), (0, -1)] {
+            let step = Coord::new(dx, dy);
+            scans.push(Scan::new(
+                hidden + step * axis,
+                hidden + step * (2 * axis - 1),
+            ));
+        }
+        for (dx, dy) in [(1, 1), (1, -1), (-1, 1), (-1, -1)] {
+            scans.push(Scan::new(
+                hidden + Coord::new(dx, dy) * diagonal,
+                hidden + Coord::new(dx * 2 * diagonal, dy * (2 * diagonal - 1)),
+            ));
+        }
+        // Sensors crowd each other out, give up on the last few.
+        for _ in 0..size * 20 {
+            if scans.len() == 8 + size {
+                break;
+            }
+            let distance = rng.signed(1..100_000);
+            let side = if rng.chance(50) { 1 } else { -1 };
+            let offset = 3 * diagonal + distance as isize + rng.signed(0..1_000_000) as isize;
+            let dy = match rng.chance(25) {
+                true => 0,
+                false => rng.signed(-distance..distance + 1),
+            };
+            let sensor = Coord::new(hidden.x + side * offset, Y + dy as isize);
+            let dx = rng.signed(-distance..distance + 1);
+            let dy = (distance - dx.abs()) * if rng.chance(50) { 1 } else { -1 };
+            let scan = Scan::new(sensor, sensor + Coord::new(dx as isize, dy as isize));
+            if scans.iter().all(|other| other.agrees_with(&scan)) {
+                scans.push(scan);
+            }
+        }
+        Zone::from_scans(scans)
+    }
+    fn render(zone: &Self::Parsed) -> String {
+        zone.scans
+            .iter()
+            .map(|scan| {
+                format!(
+                    "Sensor at x={}, y={}: closest beacon is at x={}, y={}",
+                    scan.sensor.x, scan.sensor.y, scan.beacon.x, scan.beacon.y
+                )
+            })
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+}
+
+struct BeaconExclusionZone;
+impl Solution for BeaconExclusionZone {
+    type Parsed = Zone;
+
+    fn parse(input_text: &str) -> Result<Self::Parsed, ParseError> {
+        Zone::from_string(input_text)
+    }
+    fn part1(zone: &Self::Parsed) -> Answer {
+        not_beacon_count(zone, Y).into()
+    }
+    fn part2(zone: &Self::Parsed) -> Answer {
+        tuning_frequency(zone, LOW, HIGH).map_or(NO_DISTRESS_BEACON.into(), Answer::from)
+    }
+}
+
+pub const DAY: Day = Day::new::<BeaconExclusionZone>(15, "Beacon Exclusion Zone")
+    .with_reference::<BeaconExclusionZone>();
+
+// Example tests
+#[cfg(test)]
+mod example {
+    use super::*;
+    use crate::examples::load_example;
+
+    const Y: isize = 10;
+    const ANS_PT1: u64 = 26;
+    const LOW: isize = 0;
+    const HIGH: isize = 20;
+    const ANS_PT2: u64 = 56000011;
+
+    #[test]
+    fn test_pt1() {
+        let zone = BeaconExclusionZone::parse(&load_example(15, 1).unwrap()).unwrap();
+        assert_eq!(not_beacon_count(&zone, Y), ANS_PT1);
+    }
+
+    #[test]
+    fn test_pt2() {
+        let zone = BeaconExclusionZone::parse(&load_example(15, 1).unwrap()).unwrap();
+        assert_eq!(tuning_frequency(&zone, LOW, HIGH), Some(ANS_PT2));
+    }
+}
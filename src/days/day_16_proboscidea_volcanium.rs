@@ -0,0 +1,392 @@
+// Advent Of Code
+// https://adventofcode.com/2022/day/16
+//
+// --- Day 16: Proboscidea Volcanium ---
+// Puzzle description: puzzles/day_16.md
+
+use crate::days::Day;
+use crate::generate::{Generate, Rng};
+use crate::parse::{self, Line, ParseError};
+use crate::search;
+use crate::trace;
+use crate::{Answer, Cancel, Cancelled, Solution};
+use std::collections::BTreeMap;
+use std::fmt;
+
+#[derive(Clone, Copy, Eq, PartialEq)]
+struct Id(char, char);
+impl fmt::Debug for Id {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Id({}{})", self.0, self.1)
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+struct Valve {
+    id: usize,
+    char_id: Id,
+    flow_rate: usize,
+    char_tunnels: Vec<Id>,
+    tunnels: Vec<usize>,
+    distances: Vec<usize>,
+}
+
+fn parse_id(name: Line) -> Result<Id, ParseError> {
+    let mut chars = name.text().chars();
+    match (chars.next(), chars.next(), chars.next()) {
+        (Some(a), Some(b), None) if a.is_ascii_uppercase() && b.is_ascii_uppercase() => {
+            Ok(Id(a, b))
+        }
+        _ => Err(name.error("a valve name of two capital letters")),
+    }
+}
+
+// Opened valves are the bits of a `u64`, one of them maybe taken by AA.
+const MAX_WORTH_OPENING: usize = 63;
+
+fn valve_name(id: Id) -> String {
+    format!("{}{}", id.0, id.1)
+}
+
+fn parse_input(input_text: &str) -> Result<Vec<Valve>, ParseError> {
+    let mut valves = vec![];
+    let mut tunnel_names = vec![];
+    let lines = parse::lines(input_text.trim_end()).collect::<Vec<Line>>();
+    for (id, line) in lines.iter().enumerate() {
+        let (name, rest) = line.strip_prefix("Valve ")?.split_once(" ")?;
+        let char_id = parse_id(name)?;
+        let flow_rate = rest.field("rate=")?;
+        // "tunnel leads to valve AA" or "tunnels lead to valves AA, BB"
+        let names = rest.after("to valve")?;
+        let names = names.strip_prefix("s").unwrap_or(names);
+        let names = names.split(",").map(|t| t.trim()).collect::<Vec<Line>>();
+        let char_tunnels = names
+            .iter()
+            .map(|t| parse_id(*t))
+            .collect::<Result<Vec<Id>, ParseError>>()?;
+        let tunnels = vec![];
+        let distances = vec![1; char_tunnels.len()];
+        valves.push(Valve {
+            id,
+            char_id,
+            flow_rate,
+            char_tunnels,
+            tunnels,
+            distances,
+        });
+        tunnel_names.push(names);
+    }
+    let id_map = valves
+        .iter()
+        .map(|v| (v.char_id, v.id))
+        .collect::<Vec<(Id, usize)>>();
+    for (v, names) in valves.iter_mut().zip(tunnel_names) {
+        for (char_id, name) in v.char_tunnels.clone().into_iter().zip(names) {
+            let id = id_map
+                .iter()
+                .find(|m| m.0 == char_id)
+                .ok_or_else(|| name.error("a valve described in the input"))?
+                .1;
+            v.tunnels.push(id);
+        }
+    }
+    if !valves.iter().any(|v| v.char_id == Id('A', 'A')) {
+        return Err(parse::first_line(input_text).error("a valve named AA"));
+    }
+    if let Some(v) = valves
+        .iter()
+        .filter(|v| v.flow_rate > 0)
+        .nth(MAX_WORTH_OPENING)
+    {
+        return Err(lines[v.id].error(format!(
+            "at most {} valves with a flow rate",
+            MAX_WORTH_OPENING
+        )));
+    }
+    // The search only knows the distances between the valves worth opening.
+    let important = valves
+        .iter()
+        .filter(|v| v.flow_rate > 0 || v.char_id == Id('A', 'A'))
+        .map(|v| v.id)
+        .collect::<Vec<usize>>();
+    let graph = search::from_fn(|id: &usize| valves[*id].tunnels.iter().map(|t| (*t, 1)));
+    for from in &important {
+        let reachable = search::distances(&graph, *from);
+        if let Some(to) = important.iter().find(|to| !reachable.contains_key(to)) {
+            return Err(lines[*from].error(format!(
+                "a way through the tunnels to valve {}",
+                valve_name(valves[*to].char_id)
+            )));
+        }
+    }
+    Ok(valves)
+}
+
+fn simplify_graph(valves: &[Valve], important: Vec<Valve>) -> Vec<Valve> {
+    let char_tunnels = important.iter().map(|v| v.char_id).collect::<Vec<Id>>();
+    let tunnels = important.iter().map(|v| v.id).collect::<Vec<usize>>();
+    let graph = search::from_fn(|id: &usize| valves[*id].tunnels.iter().map(|t| (*t, 1)));
+    let mut new_valves = important
+        .iter()
+        .map(|v| {
+            let shortest = search::distances(&graph, v.id);
+            Valve {
+                id: v.id,
+                char_id: v.char_id,
+                flow_rate: v.flow_rate,
+                char_tunnels: char_tunnels.clone(),
+                tunnels: tunnels.clone(),
+                distances: tunnels.iter().map(|t| shortest[t]).collect(),
+            }
+        })
+        .collect::<Vec<Valve>>();
+    let id_map = new_valves
+        .iter()
+        .enumerate()
+        .map(|(i, v)| (v.id, i))
+        .collect::<Vec<(usize, usize)>>();
+    new_valves.iter_mut().for_each(|v| {
+        v.id = id_map.iter().find(|m| m.0 == v.id).unwrap().1;
+        v.tunnels = v
+            .tunnels
+            .iter()
+            .map(|t| id_map.iter().find(|m| m.0 == *t).unwrap().1)
+            .collect()
+    });
+    new_valves
+}
+
+// Best flow a single explorer can release for each set of opened valves, and
+// whether the search got to the end before being cancelled.
+fn best_flows(valves: &[Valve], timeout: usize, cancel: &Cancel) -> (BTreeMap<u64, usize>, bool) {
+    // AA is where we start, whether or not it's worth opening.
+    let important = valves
+        .iter()
+        .filter(|v| v.flow_rate > 0 || v.char_id == Id('A', 'A'))
+        .cloned()
+        .collect::<Vec<Valve>>();
+    let valves = simplify_graph(valves, important);
+    let start_valve = valves.iter().find(|v| v.char_id == Id('A', 'A')).unwrap();
+    trace!(Debug, "Valves worth opening: {:?}", valves);
+    let mut best_flows = BTreeMap::new();
+    let done = _best_flows(
+        start_valve.id,
+        &valves,
+        timeout,
+        0,
+        0,
+        &mut best_flows,
+        cancel,
+    );
+    (best_flows, done)
+}
+fn _best_flows(
+    src_idx: usize,
+    valves: &[Valve],
+    timeout: usize,
+    open: u64,
+    flow: usize,
+    best_flows: &mut BTreeMap<u64, usize>,
+    cancel: &Cancel,
+) -> bool {
+    if cancel.is_cancelled() {
+        return false;
+    }
+    let best = best_flows.entry(open).or_insert(0);
+    *best = (*best).max(flow);
+    let valve = &valves[src_idx];
+    valves
+        .iter()
+        .enumerate()
+        .filter(|(i, v)| v.flow_rate > 0 && open & (1 << i) == 0)
+        .filter(|(i, _)| timeout > valve.distances[*i] + 1)
+        .all(|(i, v)| {
+            let next_time = timeout - 1 - valve.distances[i];
+            _best_flows(
+                v.id,
+                valves,
+                next_time,
+                open | (1 << i),
+                flow + v.flow_rate * next_time,
+                best_flows,
+                cancel,
+            )
+        })
+}
+
+fn maximize_flow_rate(best_flows: &BTreeMap<u64, usize>) -> usize {
+    best_flows.values().max().copied().unwrap_or(0)
+}
+
+// Me and the elephant open disjoint sets, so the answer is the best pair of
+// non overlapping sets.
+fn maximize_flow_rate_with_elephant(best_flows: &BTreeMap<u64, usize>) -> usize {
+    best_flows
+        .iter()
+        .flat_map(|(me_open, me_flow)| {
+            best_flows
+                .iter()
+                .filter(move |(ele_open, _)| *me_open & **ele_open == 0)
+                .map(move |(_, ele_flow)| me_flow + ele_flow)
+        })
+        .max()
+        .unwrap_or(0)
+}
+
+// The best flow of the search, or the best found so far if it was cancelled.
+fn best_flow(
+    valves: &[Valve],
+    timeout: usize,
+    cancel: &Cancel,
+    maximize: fn(&BTreeMap<u64, usize>) -> usize,
+) -> Result<Answer, Cancelled> {
+    let (best_flows, done) = best_flows(valves, timeout, cancel);
+    let best = maximize(&best_flows);
+    match done {
+        true => Ok(best.into()),
+        false => Err(Cancelled {
+            progress: Some(format!("best so far {}", best)),
+        }),
+    }
+}
+
+struct ProboscideaVolcanium;
+impl Solution for ProboscideaVolcanium {
+    type Parsed = Vec<Valve>;
+
+    fn parse(input_text: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input_text)
+    }
+    fn part1(valves: &Self::Parsed) -> Answer {
+        Self::part1_cancellable(valves, &Cancel::default()).expect("Never cancelled")
+    }
+    fn part2(valves: &Self::Parsed) -> Answer {
+        Self::part2_cancellable(valves, &Cancel::default()).expect("Never cancelled")
+    }
+    fn part1_cancellable(valves: &Self::Parsed, cancel: &Cancel) -> Result<Answer, Cancelled> {
+        let timeout = 30;
+        best_flow(valves, timeout, cancel, maximize_flow_rate)
+    }
+    fn part2_cancellable(valves: &Self::Parsed, cancel: &Cancel) -> Result<Answer, Cancelled> {
+        let timeout = 26;
+        best_flow(valves, timeout, cancel, maximize_flow_rate_with_elephant)
+    }
+}
+
+impl Generate for ProboscideaVolcanium {
+    // A connected cave with a stuck valve AA to start from and at most 15
+    // valves worth opening, like the puzzle's.
+    fn generate(rng: &mut Rng, size: usize) -> Self::Parsed {
+        let count = size.clamp(2, 26 * 26);
+        let letters = ('A'..='Z').collect::<Vec<char>>();
+        let mut names = letters
+            .iter()
+            .flat_map(|a| letters.iter().map(|b| Id(*a, *b)))
+            .filter(|id| *id != Id('A', 'A'))
+            .collect::<Vec<Id>>();
+        rng.shuffle(&mut names);
+        names.truncate(count - 1);
+        names.push(Id('A', 'A'));
+        rng.shuffle(&mut names);
+        // A random tree with a few shortcuts.
+        let mut tunnels = vec![vec![]; count];
+        let mut connect = |a: usize, b: usize| {
+            if a != b && !tunnels[a].contains(&b) {
+                tunnels[a].push(b);
+                tunnels[b].push(a);
+            }
+        };
+        for valve in 1..count {
+            connect(valve, rng.range(0..valve));
+        }
+        for _ in 0..count / 4 {
+            connect(rng.range(0..count), rng.range(0..count));
+        }
+        let mut flowing = (0..count)
+            .filter(|v| names[*v] != Id('A', 'A'))
+            .collect::<Vec<usize>>();
+        rng.shuffle(&mut flowing);
+        flowing.truncate(rng.range(1..count.min(16)));
+        tunnels
+            .into_iter()
+            .enumerate()
+            .map(|(id, tunnels)| Valve {
+                id,
+                char_id: names[id],
+                flow_rate: match flowing.contains(&id) {
+                    true => rng.range(1..26),
+                    false => 0,
+                },
+                char_tunnels: tunnels.iter().map(|t| names[*t]).collect(),
+                distances: vec![1; tunnels.len()],
+                tunnels,
+            })
+            .collect()
+    }
+    fn render(valves: &Self::Parsed) -> String {
+        valves
+            .iter()
+            .map(|v| {
+                let tunnels = v
+                    .char_tunnels
+                    .iter()
+                    .map(|id| valve_name(*id))
+                    .collect::<Vec<String>>();
+                let leads = match tunnels.len() {
+                    1 => "tunnel leads to valve",
+                    _ => "tunnels lead to valves",
+                };
+                format!(
+                    "Valve {} has flow rate={}; {} {}",
+                    valve_name(v.char_id),
+                    v.flow_rate,
+                    leads,
+                    tunnels.join(", ")
+                )
+            })
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+}
+
+pub const DAY: Day = Day::new::<ProboscideaVolcanium>(16, "Proboscidea Volcanium");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Valves AA, AB, AC, ... in a row, each worth opening but AA.
+    fn corridor(len: usize) -> String {
+        let names = ('A'..='Z')
+            .flat_map(|a| ('A'..='Z').map(move |b| format!("{}{}", a, b)))
+            .take(len)
+            .collect::<Vec<String>>();
+        (0..len)
+            .map(|i| {
+                let tunnels = [i.wrapping_sub(1), i + 1]
+                    .iter()
+                    .filter_map(|n| names.get(*n).cloned())
+                    .collect::<Vec<String>>();
+                format!(
+                    "Valve {} has flow rate={}; tunnels lead to valves {}",
+                    names[i],
+                    (i > 0) as usize,
+                    tunnels.join(", ")
+                )
+            })
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn rejects_caves_the_search_cant_handle() {
+        assert!(parse_input(&corridor(MAX_WORTH_OPENING + 1)).is_ok());
+        assert!(parse_input(&corridor(MAX_WORTH_OPENING + 2)).is_err());
+
+        let disconnected = "Valve AA has flow rate=0; tunnel leads to valve BB
+Valve BB has flow rate=10; tunnel leads to valve AA
+Valve CC has flow rate=20; tunnel leads to valve CC";
+        let err = parse_input(disconnected).unwrap_err();
+        assert!(err.to_string().contains("valve CC"), "{}", err);
+    }
+}
@@ -0,0 +1,391 @@
+// Advent Of Code
+// https://adventofcode.com/2022/day/17
+//
+// --- Day 17: Pyroclastic Flow ---
+// Puzzle description: puzzles/day_17.md
+
+use crate::days::Day;
+use crate::generate::{Generate, Rng};
+use crate::geom::{Direction4, Point2};
+use crate::grid::Grid;
+use crate::parse::{self, ParseError};
+use crate::trace;
+use crate::{Answer, Cancel, Cancelled, Solution};
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+
+const WIDTH: usize = 7;
+const LEFT_BORDER: usize = 2;
+const DROP_HEIGHT: usize = 3;
+// Chamber rows count upwards from the floor, while `Direction4` steps count
+// them down like on a screen: falling is a step up, towards row 0.
+const FALL: Direction4 = Direction4::Up;
+// How deep into the tower its surface is looked at. A cycle only counts once
+// every rock in it came to rest within that depth, see `tower_height`.
+const SURFACE_DEPTH: usize = 64;
+const ROCKS: &str = "####
+
+.#.
+###
+.#.
+
+..#
+..#
+###
+
+#
+#
+#
+#
+
+##
+##";
+
+type Coord = Point2<usize>;
+
+#[derive(Clone)]
+struct Rock {
+    cells: Grid<bool>,
+}
+impl fmt::Debug for Rock {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{}", self.cells.render(|c| if *c { '#' } else { ' ' }))
+    }
+}
+impl FromStr for Rock {
+    type Err = ParseError;
+    fn from_str(shape: &str) -> Result<Self, Self::Err> {
+        let cells = parse::grid(shape, "`#` or `.`", |c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        })?;
+        Ok(Self { cells })
+    }
+}
+impl Rock {
+    // Rock cells with `y` counting up from its bottom row, like the chamber.
+    fn shape(&self) -> impl Iterator<Item = Coord> + '_ {
+        let height = self.cells.height();
+        self.cells
+            .iter()
+            .filter(|(_, c)| **c)
+            .map(move |((x, y), _)| Coord {
+                x,
+                y: height - 1 - y,
+            })
+    }
+}
+
+struct Rocks {
+    rocks: Vec<Rock>,
+    cursor: usize,
+}
+impl FromStr for Rocks {
+    type Err = ParseError;
+    fn from_str(rocks: &str) -> Result<Self, Self::Err> {
+        let rocks = rocks
+            .split("\n\n")
+            .map(|rock| rock.parse::<Rock>())
+            .collect::<Result<Vec<Rock>, ParseError>>()?;
+        Ok(Self { rocks, cursor: 0 })
+    }
+}
+impl Rocks {
+    fn next(&mut self) -> &Rock {
+        let rock = &self.rocks[self.cursor];
+        self.cursor = (self.cursor + 1) % self.rocks.len();
+        rock
+    }
+}
+
+// The chamber grows upwards: row 0 lies on the floor.
+struct RockFall {
+    chamber: Grid<bool>,
+    falling: Vec<Coord>,
+    rocks: Rocks,
+    jet: Jet,
+    left_border: usize,
+    drop_height: usize,
+    height: usize,
+}
+impl fmt::Debug for RockFall {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let top = self
+            .falling
+            .iter()
+            .map(|c| c.y + 1)
+            .max()
+            .unwrap_or(0)
+            .max(self.chamber.height());
+        let fall_str = (0..top)
+            .rev()
+            .map(|y| {
+                let line = (0..self.chamber.width())
+                    .map(|x| {
+                        if self.falling.contains(&Coord { x, y }) {
+                            '@'
+                        } else if self.chamber.get(x, y) == Some(&true) {
+                            '#'
+                        } else {
+                            '.'
+                        }
+                    })
+                    .collect::<String>();
+                format!("|{}|\n", line)
+            })
+            .collect::<String>();
+        let bottom = format!("+{}+", "-".repeat(self.chamber.width()));
+        write!(f, "\n{}{}", fall_str, bottom)
+    }
+}
+
+impl RockFall {
+    fn new(rocks: Rocks, jet: Jet, width: usize, left_border: usize, drop_height: usize) -> Self {
+        Self {
+            chamber: Grid::from_cells(width, vec![]),
+            falling: vec![],
+            rocks,
+            jet,
+            left_border,
+            drop_height,
+            height: 0,
+        }
+    }
+    fn max_total_height(&self) -> usize {
+        self.height
+    }
+    // Drops a rock and returns the lowest row it came to rest on.
+    fn drop_next(&mut self) -> usize {
+        let rock = self.rocks.next().clone();
+        let bottom = self.height + self.drop_height;
+        self.falling = rock
+            .shape()
+            .map(|c| Coord {
+                x: c.x + self.left_border,
+                y: c.y + bottom,
+            })
+            .collect();
+        loop {
+            // push
+            let side = *self.jet.next();
+            self.move_falling(side);
+
+            // drop
+            if !self.move_falling(FALL) {
+                return self.lock_falling();
+            }
+        }
+    }
+
+    fn is_free(&self, c: &Coord) -> bool {
+        c.x < self.chamber.width() && self.chamber.get(c.x, c.y) != Some(&true)
+    }
+
+    fn move_falling(&mut self, step: Direction4) -> bool {
+        let moved = self
+            .falling
+            .iter()
+            .map(|c| c.step(step))
+            .collect::<Option<Vec<Coord>>>();
+        match moved {
+            Some(moved) if moved.iter().all(|c| self.is_free(c)) => {
+                self.falling = moved;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    // What decides how the next rocks fall: which rock and jet come next, and
+    // the top rows of the tower, one bit per cell. Columns alone aren't
+    // enough, rocks can slide under overhangs.
+    fn fingerprint(&self) -> (usize, usize, Vec<u8>) {
+        let surface = (self.height.saturating_sub(SURFACE_DEPTH)..self.height)
+            .rev()
+            .map(|y| {
+                (0..WIDTH)
+                    .filter(|x| self.chamber[(*x, y)])
+                    .fold(0, |row, x| row | 1 << x)
+            })
+            .collect();
+        (self.rocks.cursor, self.jet.cursor, surface)
+    }
+
+    fn lock_falling(&mut self) -> usize {
+        let width = self.chamber.width();
+        let falling = std::mem::take(&mut self.falling);
+        let lowest = falling.iter().map(|c| c.y).min().unwrap_or(self.height);
+        for c in falling {
+            while self.chamber.height() <= c.y {
+                self.chamber.push_row(vec![false; width]);
+            }
+            self.chamber[(c.x, c.y)] = true;
+            self.height = self.height.max(c.y + 1);
+        }
+        lowest
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Jet {
+    pattern: Vec<Direction4>,
+    cursor: usize,
+}
+impl FromStr for Jet {
+    type Err = ParseError;
+    fn from_str(pattern: &str) -> Result<Self, Self::Err> {
+        let line = parse::first_line(pattern).trim();
+        if line.is_empty() {
+            return Err(line.error("a pattern of `<` and `>`"));
+        }
+        let pattern = line
+            .text()
+            .char_indices()
+            .map(|(i, c)| match c {
+                '<' => Ok(Direction4::Left),
+                '>' => Ok(Direction4::Right),
+                _ => Err(line.skip(i).error("`<` or `>`")),
+            })
+            .collect::<Result<Vec<Direction4>, ParseError>>()?;
+        Ok(Self { pattern, cursor: 0 })
+    }
+}
+impl Jet {
+    fn next(&mut self) -> &Direction4 {
+        let side = &self.pattern[self.cursor];
+        self.cursor = (self.cursor + 1) % self.pattern.len();
+        side
+    }
+}
+
+// Rocks dropped between two looks at `cancel`.
+const CANCEL_CHECK: usize = 1024;
+
+// Drops rocks until the fall repeats itself, then skips as many whole cycles
+// as fit and drops the rest. Two fingerprints matching isn't enough on its
+// own, what's below them could differ. But if no rock since the first one
+// came to rest below its surface, those rocks never touched anything else,
+// and the ones after the second fall the same way, shifted up.
+fn tower_height(jet: &Jet, dropped_rocks: usize, cancel: &Cancel) -> Result<u64, Cancelled> {
+    let rocks = ROCKS.parse::<Rocks>().expect("All good with rocks");
+    let mut rock_fall = RockFall::new(rocks, jet.clone(), WIDTH, LEFT_BORDER, DROP_HEIGHT);
+    let mut seen = HashMap::new();
+    let mut landings = vec![];
+    let mut skipped = None;
+    let mut dropped = 0;
+    while dropped < dropped_rocks {
+        if dropped % CANCEL_CHECK == 0 && cancel.is_cancelled() {
+            return Err(Cancelled {
+                progress: Some(format!(
+                    "height {} after {} rocks",
+                    rock_fall.max_total_height(),
+                    dropped
+                )),
+            });
+        }
+        landings.push(rock_fall.drop_next());
+        dropped += 1;
+        if skipped.is_some() {
+            continue;
+        }
+        let height = rock_fall.max_total_height();
+        if let Some((before, height_before)) =
+            seen.insert(rock_fall.fingerprint(), (dropped, height))
+        {
+            // The row under a rock's lowest cell is the deepest it looked at.
+            let lowest = landings[before..].iter().min().copied().unwrap_or(0);
+            if lowest + SURFACE_DEPTH <= height_before {
+                continue;
+            }
+            let (cycle, growth) = (dropped - before, height - height_before);
+            let cycles = (dropped_rocks - dropped) / cycle;
+            trace!(
+                Debug,
+                "Every {} rocks from rock {} add {} to the height, skipping {} cycles",
+                cycle,
+                before,
+                growth,
+                cycles
+            );
+            dropped += cycles * cycle;
+            skipped = Some(cycles as u64 * growth as u64);
+        }
+    }
+    Ok(rock_fall.max_total_height() as u64 + skipped.unwrap_or(0))
+}
+
+struct PyroclasticFlow;
+impl Solution for PyroclasticFlow {
+    type Parsed = Jet;
+
+    fn parse(input_text: &str) -> Result<Self::Parsed, ParseError> {
+        input_text.parse::<Jet>()
+    }
+    fn part1(jet: &Self::Parsed) -> Answer {
+        Self::part1_cancellable(jet, &Cancel::default()).expect("Never cancelled")
+    }
+    fn part2(jet: &Self::Parsed) -> Answer {
+        Self::part2_cancellable(jet, &Cancel::default()).expect("Never cancelled")
+    }
+    fn part1_cancellable(jet: &Self::Parsed, cancel: &Cancel) -> Result<Answer, Cancelled> {
+        const DROPPED_ROCKS: usize = 2022;
+        tower_height(jet, DROPPED_ROCKS, cancel).map(Answer::from)
+    }
+    fn part2_cancellable(jet: &Self::Parsed, cancel: &Cancel) -> Result<Answer, Cancelled> {
+        const DROPPED_ROCKS: usize = 1000000000000;
+        tower_height(jet, DROPPED_ROCKS, cancel).map(Answer::from)
+    }
+}
+
+impl Generate for PyroclasticFlow {
+    fn generate(rng: &mut Rng, size: usize) -> Self::Parsed {
+        let pattern = (0..size * 10)
+            .map(|_| *rng.pick(&[Direction4::Left, Direction4::Right]))
+            .collect();
+        Jet { pattern, cursor: 0 }
+    }
+    fn render(jet: &Self::Parsed) -> String {
+        jet.pattern
+            .iter()
+            .map(|side| if *side == Direction4::Left { '<' } else { '>' })
+            .collect()
+    }
+}
+
+pub const DAY: Day = Day::new::<PyroclasticFlow>(17, "Pyroclastic Flow");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Every rock dropped, no cycle skipped.
+    fn dropped_height(jet: &Jet, dropped_rocks: usize) -> u64 {
+        let rocks = ROCKS.parse::<Rocks>().expect("All good with rocks");
+        let mut rock_fall = RockFall::new(rocks, jet.clone(), WIDTH, LEFT_BORDER, DROP_HEIGHT);
+        for _ in 0..dropped_rocks {
+            rock_fall.drop_next();
+        }
+        rock_fall.max_total_height() as u64
+    }
+
+    #[test]
+    fn skipped_cycles_match_dropping_every_rock() {
+        let mut rng = Rng::new(17);
+        for size in 1..=4 {
+            for _ in 0..5 {
+                let jet = PyroclasticFlow::generate(&mut rng, size);
+                for dropped_rocks in [2022, 5000] {
+                    assert_eq!(
+                        tower_height(&jet, dropped_rocks, &Cancel::default()),
+                        Ok(dropped_height(&jet, dropped_rocks)),
+                        "{} rocks in {}",
+                        dropped_rocks,
+                        PyroclasticFlow::render(&jet)
+                    );
+                }
+            }
+        }
+    }
+}
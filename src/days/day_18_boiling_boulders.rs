@@ -0,0 +1,164 @@
+// Advent Of Code
+// https://adventofcode.com/2022/day/18
+//
+// --- Day 18: Boiling Boulders ---
+// Puzzle description: puzzles/day_18.md
+
+use crate::days::Day;
+use crate::generate::{Generate, Rng};
+use crate::geom::Point3;
+use crate::parse::{self, Line, ParseError};
+use crate::{Answer, Solution};
+
+type Cube = Point3<isize>;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct LavaStream {
+    max: Cube,
+    boulders: Vec<u8>,
+}
+impl LavaStream {
+    // MEH. cOUld dO BeTteR
+    fn new(max: Cube) -> Self {
+        let length = (max.x + 2) * (max.y + 2) * (max.z + 2);
+        LavaStream {
+            max,
+            boulders: vec![0; length as usize],
+        }
+    }
+    fn get_offset(stream: &Self, cube: &Cube) -> Option<usize> {
+        let Cube { x, y, z } = *cube;
+        let max = stream.max + Cube::new(1, 1, 1);
+        if x < 0 || y < 0 || z < 0 || x > max.x || y > max.y || z > max.z {
+            return None;
+        }
+        Some((x + (max.x + 1) * y + (max.x + 1) * (max.y + 1) * z) as usize)
+    }
+    fn set_boulder(&mut self, value: u8, cube: &Cube) {
+        let offest = Self::get_offset(self, cube)
+            .unwrap_or_else(|| panic!("Can't calculate offest for boulder {cube}"));
+        self.boulders[offest] = value
+    }
+    fn get_boulder(&self, cube: &Cube) -> Option<u8> {
+        Self::get_offset(self, cube).map(|offset| self.boulders[offset])
+    }
+    fn from_sparse(lava_in: &[Cube]) -> Self {
+        let max_x = lava_in.iter().map(|c| c.x).max().unwrap();
+        let max_y = lava_in.iter().map(|c| c.y).max().unwrap();
+        let max_z = lava_in.iter().map(|c| c.z).max().unwrap();
+        let mut lava_stream = LavaStream::new(Cube::new(max_x + 1, max_y + 1, max_z + 1));
+        lava_in.iter().for_each(|cube| {
+            // Offset by one to leave room for searching bellow the 0th element.
+            lava_stream.set_boulder(1, &(*cube + Cube::new(1, 1, 1)));
+        });
+        lava_stream
+    }
+    fn surface_area(stream: &Self) -> usize {
+        let color = 0;
+        (1..=(stream.max.z + 1))
+            .flat_map(|z| {
+                (1..=(stream.max.y + 1))
+                    .flat_map(move |y| (1..=(stream.max.x + 1)).map(move |x| Cube::new(x, y, z)))
+            })
+            .map(|cube| Self::boulder_exposed_area(stream, &cube, color))
+            .sum::<usize>()
+    }
+    fn boulder_exposed_area(stream: &Self, cube: &Cube, color: u8) -> usize {
+        if stream.get_boulder(cube) == Some(color) {
+            return 0;
+        }
+        cube.neighbours6()
+            .iter()
+            .filter(|side| stream.get_boulder(side) == Some(color))
+            .count()
+    }
+    fn outer_surface_area(stream: &Self) -> usize {
+        let fill_stream = &mut stream.clone();
+        const FILL_COLOR: u8 = u8::MAX;
+        Self::boundary_fill(fill_stream, Cube::new(1, 1, 1), FILL_COLOR);
+        fill_stream.boulders = fill_stream
+            .boulders
+            .iter()
+            .map(|color| match color {
+                0 => 1,
+                &FILL_COLOR => 0,
+                c => *c,
+            })
+            .collect();
+        LavaStream::surface_area(fill_stream)
+    }
+    fn boundary_fill(stream: &mut Self, start: Cube, fill_color: u8) {
+        let mut to_fill = vec![start];
+
+        while let Some(cube) = to_fill.pop() {
+            stream.set_boulder(fill_color, &cube);
+            let sides = cube.neighbours6();
+            to_fill.extend(
+                sides
+                    .into_iter()
+                    .filter(|side| stream.get_boulder(side) == Some(0)),
+            );
+        }
+    }
+}
+
+fn parse_cube(line: Line) -> Result<Cube, ParseError> {
+    // The stream is stored densely, so coordinates can't be negative or
+    // arbitrarily large.
+    let coords = line
+        .split(",")
+        .map(|i| i.parse::<u8>().map(|i| i as isize))
+        .collect::<Result<Vec<isize>, ParseError>>()?;
+    match coords[..] {
+        [x, y, z] => Ok(Cube::new(x, y, z)),
+        _ => Err(line.error("three coordinates: x,y,z")),
+    }
+}
+
+fn parse_input(input_text: &str) -> Result<LavaStream, ParseError> {
+    let lava_in = parse::lines(input_text.trim_end())
+        .map(parse_cube)
+        .collect::<Result<Vec<Cube>, ParseError>>()?;
+    if lava_in.is_empty() {
+        return Err(parse::first_line(input_text).error("lava cubes"));
+    }
+    Ok(LavaStream::from_sparse(&lava_in))
+}
+
+struct BoilingBoulders;
+impl Solution for BoilingBoulders {
+    type Parsed = LavaStream;
+
+    fn parse(input_text: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input_text)
+    }
+    fn part1(lava_stream: &Self::Parsed) -> Answer {
+        LavaStream::surface_area(lava_stream).into()
+    }
+    fn part2(lava_stream: &Self::Parsed) -> Answer {
+        LavaStream::outer_surface_area(lava_stream).into()
+    }
+}
+
+impl Generate for BoilingBoulders {
+    // Cubes scattered through a box twice their number, pockets and all.
+    fn generate(rng: &mut Rng, size: usize) -> Self::Parsed {
+        let side = (1..).find(|side| side * side * side >= size * 2).unwrap();
+        let mut coordinate = || rng.range(0..side) as isize;
+        let cubes = (0..size.max(1))
+            .map(|_| Cube::new(coordinate(), coordinate(), coordinate()))
+            .collect::<Vec<Cube>>();
+        LavaStream::from_sparse(&cubes)
+    }
+    fn render(lava_stream: &Self::Parsed) -> String {
+        let max = lava_stream.max;
+        (0..max.z)
+            .flat_map(|z| (0..max.y).flat_map(move |y| (0..max.x).map(move |x| Cube::new(x, y, z))))
+            .filter(|cube| lava_stream.get_boulder(&(*cube + Cube::new(1, 1, 1))) == Some(1))
+            .map(|cube| format!("{},{},{}", cube.x, cube.y, cube.z))
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+}
+
+pub const DAY: Day = Day::new::<BoilingBoulders>(18, "Boiling Boulders");
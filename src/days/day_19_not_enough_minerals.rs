@@ -0,0 +1,350 @@
+// Advent Of Code
+// https://adventofcode.com/2022/day/19
+//
+// --- Day 19: Not Enough Minerals ---
+// Puzzle description: puzzles/day_19.md
+
+use crate::days::Day;
+use crate::generate::{Generate, Rng};
+use crate::parse::{self, Line, ParseError};
+use crate::trace;
+use crate::{Answer, Solution};
+
+const ORE: &str = "ore";
+const CLAY: &str = "clay";
+const OBSIDIAN: &str = "obsidian";
+const GEODE: &str = "geode";
+
+// In order of worth, geodes last.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+enum Resource {
+    Ore,
+    Clay,
+    Obsidian,
+    Geode,
+}
+impl Resources {
+    fn count(&self, resource: &Resource) -> usize {
+        match resource {
+            Resource::Ore => self.ore,
+            Resource::Clay => self.clay,
+            Resource::Obsidian => self.obsidian,
+            Resource::Geode => self.geode,
+        }
+    }
+    fn add(&mut self, resource: &Resource, amount: usize) {
+        match resource {
+            Resource::Ore => self.ore += amount,
+            Resource::Clay => self.clay += amount,
+            Resource::Obsidian => self.obsidian += amount,
+            Resource::Geode => self.geode += amount,
+        }
+    }
+    // What `robots` mine in `minutes`.
+    fn mine(&mut self, robots: &Resources, minutes: usize) {
+        self.ore += robots.ore * minutes;
+        self.clay += robots.clay * minutes;
+        self.obsidian += robots.obsidian * minutes;
+        self.geode += robots.geode * minutes;
+    }
+    fn remove(&mut self, ore: usize, clay: usize, obsidian: usize, geode: usize) {
+        self.ore -= ore;
+        self.clay -= clay;
+        self.obsidian -= obsidian;
+        self.geode -= geode;
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Robot {
+    mines: Resource,
+    ore: usize,
+    clay: usize,
+    obsidian: usize,
+}
+
+impl Robot {
+    // Minutes of mining until `state` can afford this robot, unless its robots
+    // never mine what it needs.
+    fn wait(&self, state: &MineState) -> Option<usize> {
+        let (pile, robots) = (&state.pile, &state.mining_robots);
+        [
+            (self.ore, pile.ore, robots.ore),
+            (self.clay, pile.clay, robots.clay),
+            (self.obsidian, pile.obsidian, robots.obsidian),
+        ]
+        .into_iter()
+        .map(
+            |(cost, pile, robots)| match (cost.saturating_sub(pile), robots) {
+                (0, _) => Some(0),
+                (_, 0) => None,
+                (missing, robots) => Some(missing.div_ceil(robots)),
+            },
+        )
+        .try_fold(0, |wait, robot_wait| Some(wait.max(robot_wait?)))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Blueprint {
+    id: u8,
+    robots: Vec<Robot>,
+}
+
+#[derive(Debug, Clone, Copy, Eq, Ord, PartialEq, PartialOrd)]
+struct Resources {
+    geode: usize,
+    obsidian: usize,
+    clay: usize,
+    ore: usize,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+struct MineState {
+    pile: Resources,
+    mining_robots: Resources,
+    time: usize,
+}
+
+fn parse_resource(name: Line) -> Result<Resource, ParseError> {
+    match name.text() {
+        ORE => Ok(Resource::Ore),
+        CLAY => Ok(Resource::Clay),
+        OBSIDIAN => Ok(Resource::Obsidian),
+        GEODE => Ok(Resource::Geode),
+        _ => Err(name.error("ore, clay, obsidian or geode")),
+    }
+}
+
+// Each obsidian robot costs 3 ore and 14 clay
+fn parse_robot(sentence: Line) -> Result<Robot, ParseError> {
+    let (mines, costs) = sentence.after("Each ")?.split_once(" robot costs ")?;
+    let mut robot = Robot {
+        mines: parse_resource(mines)?,
+        ore: 0,
+        clay: 0,
+        obsidian: 0,
+    };
+    for cost in costs.split(" and ") {
+        let (ammount, resource_name) = cost.split_once(" ")?;
+        let ammount = ammount.parse::<usize>()?;
+        match parse_resource(resource_name)? {
+            Resource::Ore => robot.ore = ammount,
+            Resource::Clay => robot.clay = ammount,
+            Resource::Obsidian => robot.obsidian = ammount,
+            Resource::Geode => return Err(resource_name.error("ore, clay or obsidian")),
+        };
+    }
+    Ok(robot)
+}
+
+fn parse_input(input_text: &str) -> Result<Vec<Blueprint>, ParseError> {
+    parse::lines(input_text)
+        .map(|line| {
+            let (blue_id, blueprint) = line.split_once(":")?;
+            let robots = blueprint
+                .trim()
+                .strip_suffix(".")?
+                .split(". ")
+                .map(parse_robot)
+                .collect::<Result<Vec<Robot>, ParseError>>()?;
+            Ok(Blueprint {
+                id: blue_id.field("Blueprint")?,
+                robots,
+            })
+        })
+        .collect()
+}
+
+fn find_best_blueprint(blueprints: &[Blueprint], minutes: usize) -> Vec<(u8, usize)> {
+    let starting_robots = Resources {
+        ore: 1,
+        clay: 0,
+        obsidian: 0,
+        geode: 0,
+    };
+    let resource_piles = blueprints
+        .iter()
+        .map(|blueprint| optimize_mining(blueprint, minutes, &starting_robots))
+        .collect::<Vec<Resources>>();
+    trace!(Trace, "Resources mined: {:?}", resource_piles);
+    let results = blueprints
+        .iter()
+        .zip(resource_piles.iter())
+        .map(|(blueprint, mined)| (blueprint.id, mined.geode))
+        .collect::<Vec<(u8, usize)>>();
+    trace!(Debug, "Geodes per blueprint: {:?}", results);
+    results
+}
+fn optimize_mining(
+    blueprint: &Blueprint,
+    minutes: usize,
+    starting_robots: &Resources,
+) -> Resources {
+    // A robot a minute can spend all that any robot costs: more are no use.
+    let max_cost = |cost: fn(&Robot) -> usize| blueprint.robots.iter().map(cost).max();
+    let max_robots = Resources {
+        ore: max_cost(|robot| robot.ore).unwrap_or(0),
+        clay: max_cost(|robot| robot.clay).unwrap_or(0),
+        obsidian: max_cost(|robot| robot.obsidian).unwrap_or(0),
+        geode: usize::MAX,
+    };
+    let initial_state = MineState {
+        pile: Resources {
+            ore: 0,
+            clay: 0,
+            obsidian: 0,
+            geode: 0,
+        },
+        mining_robots: *starting_robots,
+        time: 0,
+    };
+    // Geode robots first: good plans early prune more.
+    let mut blueprint = blueprint.clone();
+    blueprint.robots.sort_by(|a, b| b.mines.cmp(&a.mines));
+    let mut best_pile = initial_state.pile;
+    best_plan(
+        &blueprint,
+        &max_robots,
+        minutes,
+        &initial_state,
+        &mut best_pile,
+    );
+    best_pile
+}
+
+// Depth first over which robot to build next, mining until it's affordable,
+// and giving up on plans that can't beat `best_pile` any more.
+fn best_plan(
+    blueprint: &Blueprint,
+    max_robots: &Resources,
+    minutes: usize,
+    state: &MineState,
+    best_pile: &mut Resources,
+) {
+    let left = minutes - state.time;
+    let mut idle = state.pile;
+    idle.mine(&state.mining_robots, left);
+    if idle.geode > best_pile.geode {
+        *best_pile = idle;
+    }
+    // Even a new geode robot every minute left wouldn't do better.
+    let optimistic = idle.geode + left * left.saturating_sub(1) / 2;
+    if optimistic <= best_pile.geode {
+        return;
+    }
+    for robot in &blueprint.robots {
+        if state.mining_robots.count(&robot.mines) >= max_robots.count(&robot.mines) {
+            continue;
+        }
+        let Some(wait) = robot.wait(state) else {
+            continue;
+        };
+        // Built at the end of the last minute, it wouldn't mine anything.
+        if state.time + wait + 1 >= minutes {
+            continue;
+        }
+        let mut next = state.clone();
+        next.pile.mine(&state.mining_robots, wait + 1);
+        next.pile.remove(robot.ore, robot.clay, robot.obsidian, 0);
+        next.mining_robots.add(&robot.mines, 1);
+        next.time += wait + 1;
+        best_plan(blueprint, max_robots, minutes, &next, best_pile);
+    }
+}
+
+fn quality_level(best_yield: &[(u8, usize)]) -> u64 {
+    best_yield
+        .iter()
+        .fold(0, |acc, (id, geodes)| acc + (*id as u64 * *geodes as u64))
+}
+
+struct NotEnoughMinerals;
+impl Solution for NotEnoughMinerals {
+    type Parsed = Vec<Blueprint>;
+
+    fn parse(input_text: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input_text)
+    }
+    fn part1(blueprints: &Self::Parsed) -> Answer {
+        const MINUTES: usize = 24;
+        let best_yield = find_best_blueprint(blueprints, MINUTES);
+        quality_level(&best_yield).into()
+    }
+    fn part2(blueprints: &Self::Parsed) -> Answer {
+        // The elephants ate the rest.
+        const BLUEPRINTS: usize = 3;
+        const MINUTES: usize = 32;
+        let blueprints = &blueprints[..blueprints.len().min(BLUEPRINTS)];
+        let best_yield = find_best_blueprint(blueprints, MINUTES);
+        best_yield
+            .iter()
+            .map(|(_, geodes)| *geodes as u64)
+            .product::<u64>()
+            .into()
+    }
+}
+
+impl Generate for NotEnoughMinerals {
+    // Costs in the puzzle's ranges, at least three blueprints for part two and
+    // at most as many as `u8` ids go.
+    fn generate(rng: &mut Rng, size: usize) -> Self::Parsed {
+        (1..=u8::MAX)
+            .take(size.max(3))
+            .map(|id| {
+                let (clay_cost, obsidian_cost) = (rng.range(5..21), rng.range(5..21));
+                let mut robot = |mines, clay, obsidian| Robot {
+                    mines,
+                    ore: rng.range(2..5),
+                    clay,
+                    obsidian,
+                };
+                let ore = robot(Resource::Ore, 0, 0);
+                let clay = robot(Resource::Clay, 0, 0);
+                let obsidian = robot(Resource::Obsidian, clay_cost, 0);
+                let geode = robot(Resource::Geode, 0, obsidian_cost);
+                Blueprint {
+                    id,
+                    robots: vec![ore, clay, obsidian, geode],
+                }
+            })
+            .collect()
+    }
+    fn render(blueprints: &Self::Parsed) -> String {
+        let name = |resource: &Resource| match resource {
+            Resource::Ore => ORE,
+            Resource::Clay => CLAY,
+            Resource::Obsidian => OBSIDIAN,
+            Resource::Geode => GEODE,
+        };
+        blueprints
+            .iter()
+            .map(|blueprint| {
+                let robots = blueprint
+                    .robots
+                    .iter()
+                    .map(|robot| {
+                        let costs = [
+                            (robot.ore, ORE),
+                            (robot.clay, CLAY),
+                            (robot.obsidian, OBSIDIAN),
+                        ]
+                        .iter()
+                        .filter(|(amount, _)| *amount > 0)
+                        .map(|(amount, resource)| format!("{} {}", amount, resource))
+                        .collect::<Vec<String>>();
+                        format!(
+                            "Each {} robot costs {}.",
+                            name(&robot.mines),
+                            costs.join(" and ")
+                        )
+                    })
+                    .collect::<Vec<String>>();
+                format!("Blueprint {}: {}", blueprint.id, robots.join(" "))
+            })
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+}
+
+pub const DAY: Day = Day::new::<NotEnoughMinerals>(19, "Not Enough Minerals");
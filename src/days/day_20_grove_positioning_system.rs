@@ -0,0 +1,221 @@
+// Advent Of Code
+// https://adventofcode.com/2022/day/20
+//
+// --- Day 20: Grove Positioning System ---
+// Puzzle description: puzzles/day_20.md
+
+use crate::days::Day;
+use crate::generate::{Generate, Rng};
+use crate::parse::{self, ParseError};
+use crate::reference::Reference;
+use crate::{Answer, Solution};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Decryptor {
+    message: Vec<(usize, isize)>,
+    current: Vec<(usize, isize)>,
+    rounds: usize,
+    length: isize,
+    key: isize,
+}
+impl Decryptor {
+    fn new(numbers: Vec<isize>) -> Self {
+        let message = numbers.into_iter().enumerate().collect::<Vec<_>>();
+        Self {
+            current: message.clone(),
+            length: message.len() as isize,
+            message,
+            rounds: 0,
+            key: 1,
+        }
+    }
+    fn run(&mut self, rounds: usize) {
+        for _ in 0..rounds {
+            let cursor = self.rounds % self.current.len();
+            let position = self.current.iter().position(|n| n.0 == cursor).unwrap();
+            self.move_number(position);
+            self.rounds += 1;
+        }
+    }
+    fn move_number(&mut self, position: usize) {
+        let number = self.current[position];
+        let total_offset = position as isize + number.1;
+        let final_position = total_offset.rem_euclid(self.length - 1);
+        self.current.remove(position);
+        self.current.insert(final_position as usize, number);
+    }
+    fn decrypt(&mut self, cycles: usize) -> isize {
+        self.reset();
+        for _ in 0..cycles {
+            self.run(self.length as usize);
+        }
+        let d0 = self.get_grove(0, 1000);
+        let d1 = self.get_grove(0, 2000);
+        let d2 = self.get_grove(0, 3000);
+        d0 + d1 + d2
+    }
+    fn get_grove(&self, g: isize, o: isize) -> isize {
+        let position = self.current.iter().position(|n| n.1 == g).unwrap() as isize;
+        let position = (position + o).rem_euclid(self.length) as usize;
+        self.current[position].1
+    }
+    fn reset(&mut self) {
+        self.current = self
+            .message
+            .iter()
+            .map(|(i, value)| (*i, value * self.key))
+            .collect::<Vec<(usize, isize)>>();
+    }
+    fn set_key(&mut self, key: isize) {
+        self.key = key;
+        self.reset();
+    }
+}
+
+fn parse_input(input_text: &str) -> Result<Decryptor, ParseError> {
+    let numbers = parse::lines(input_text.trim_end())
+        .map(|line| line.parse::<isize>())
+        .collect::<Result<Vec<isize>, ParseError>>()?;
+    // Grove coordinates are counted from the 0.
+    if !numbers.contains(&0) {
+        return Err(parse::first_line(input_text).error("a 0 somewhere in the file"));
+    }
+    // A number moves round the `len - 1` others, and there have to be some.
+    if numbers.len() < 2 {
+        return Err(parse::first_line(input_text).error("more numbers than just the 0"));
+    }
+    Ok(Decryptor::new(numbers))
+}
+
+// Mixing in a circle, moving each number one swap at a time, `swaps(value,
+// len)` swaps in the direction of its sign.
+fn reference_mix(
+    decryptor: &Decryptor,
+    key: isize,
+    rounds: usize,
+    swaps: fn(isize, usize) -> usize,
+) -> isize {
+    let mut circle = decryptor
+        .message
+        .iter()
+        .map(|(i, value)| (*i, value * key))
+        .collect::<Vec<(usize, isize)>>();
+    let len = circle.len();
+    for _ in 0..rounds {
+        for original in 0..len {
+            let mut at = circle.iter().position(|(i, _)| *i == original).unwrap();
+            let value = circle[at].1;
+            for _ in 0..swaps(value, len) {
+                let next = match value > 0 {
+                    true => (at + 1) % len,
+                    false => (at + len - 1) % len,
+                };
+                circle.swap(at, next);
+                at = next;
+            }
+        }
+    }
+    let zero = circle.iter().position(|(_, value)| *value == 0).unwrap();
+    [1000, 2000, 3000]
+        .iter()
+        .map(|offset| circle[(zero + offset) % len].1)
+        .sum()
+}
+
+struct GrovePositioningSystem;
+impl Solution for GrovePositioningSystem {
+    type Parsed = Decryptor;
+
+    fn parse(input_text: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input_text)
+    }
+    fn part1(decryptor: &Self::Parsed) -> Answer {
+        let mut decryptor = decryptor.clone();
+        decryptor.decrypt(1).into()
+    }
+    fn part2(decryptor: &Self::Parsed) -> Answer {
+        let mut decryptor = decryptor.clone();
+        let key = 811589153;
+        let cycles = 10;
+        decryptor.set_key(key);
+        decryptor.decrypt(cycles).into()
+    }
+}
+
+impl Reference for GrovePositioningSystem {
+    // Every swap, no shortcut.
+    fn reference_part1(decryptor: &Self::Parsed) -> Answer {
+        reference_mix(decryptor, 1, 1, |value, _| value.unsigned_abs()).into()
+    }
+    // The key makes for far too many swaps to walk. A number going all the way
+    // round passes the other `len - 1` numbers and ends up where it started,
+    // so only the remainder of those laps is walked. The part 1 reference
+    // checks that shortcut, as the fast solution takes it, against every swap.
+    fn reference_part2(decryptor: &Self::Parsed) -> Answer {
+        reference_mix(decryptor, 811589153, 10, |value, len| {
+            value.unsigned_abs() % (len - 1)
+        })
+        .into()
+    }
+}
+
+impl Generate for GrovePositioningSystem {
+    // A single 0 among other numbers, which may repeat.
+    fn generate(rng: &mut Rng, size: usize) -> Self::Parsed {
+        let mut numbers = (0..size.max(2))
+            .map(|_| match rng.signed(-10000..10000) {
+                0 => 10000,
+                n => n as isize,
+            })
+            .collect::<Vec<isize>>();
+        let zero = rng.range(0..numbers.len());
+        numbers[zero] = 0;
+        Decryptor::new(numbers)
+    }
+    fn render(decryptor: &Self::Parsed) -> String {
+        decryptor
+            .message
+            .iter()
+            .map(|(_, n)| n.to_string())
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+}
+
+pub const DAY: Day = Day::new::<GrovePositioningSystem>(20, "Grove Positioning System")
+    .with_reference::<GrovePositioningSystem>();
+
+// Example tests
+#[cfg(test)]
+mod example {
+    use super::*;
+    use crate::examples::load_example;
+
+    #[test]
+    fn test_pt1_simple() {
+        const ANS_PT1_S_0: isize = 4;
+        const ANS_PT1_S_1: isize = 4;
+        const ANS_PT1_S_2: isize = 4;
+        const ANS_PT1_S_3: isize = 4;
+        const ANS_PT1_S_4: isize = 3;
+        const ANS_PT1_S_5: isize = 3;
+        const ANS_PT1_S_6: isize = 3;
+        const ANS_PT1_S_7: isize = 3;
+        let mut decryptor = parse_input(&load_example(20, 1).unwrap()).unwrap();
+        assert_eq!(decryptor.get_grove(0, 1), ANS_PT1_S_0);
+        decryptor.run(1);
+        assert_eq!(decryptor.get_grove(0, 1), ANS_PT1_S_1);
+        decryptor.run(1);
+        assert_eq!(decryptor.get_grove(0, 1), ANS_PT1_S_2);
+        decryptor.run(1);
+        assert_eq!(decryptor.get_grove(0, 1), ANS_PT1_S_3);
+        decryptor.run(1);
+        assert_eq!(decryptor.get_grove(0, 1), ANS_PT1_S_4);
+        decryptor.run(1);
+        assert_eq!(decryptor.get_grove(0, 1), ANS_PT1_S_5);
+        decryptor.run(1);
+        assert_eq!(decryptor.get_grove(0, 1), ANS_PT1_S_6);
+        decryptor.run(1);
+        assert_eq!(decryptor.get_grove(0, 1), ANS_PT1_S_7);
+    }
+}
@@ -0,0 +1,432 @@
+// Advent Of Code
+// https://adventofcode.com/2022/day/21
+//
+// --- Day 21: Monkey Math ---
+// Puzzle description: puzzles/day_21.md
+
+use crate::days::Day;
+use crate::generate::{Generate, Rng};
+use crate::parse::{self, Line, ParseError};
+use crate::reference::Reference;
+use crate::{Answer, Solution};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Clone, Copy, Ord, PartialOrd, Eq, PartialEq)]
+struct Name(char, char, char, char);
+impl fmt::Debug for Name {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}{}{}", self.0, self.1, self.2, self.3)
+    }
+}
+impl Name {
+    fn from_line(name: Line) -> Result<Name, ParseError> {
+        let chars = name.text().chars().collect::<Vec<char>>();
+        match chars[..] {
+            [a, b, c, d] if chars.iter().all(char::is_ascii_lowercase) => Ok(Name(a, b, c, d)),
+            _ => Err(name.error("a monkey name of four lowercase letters")),
+        }
+    }
+}
+const ROOT: Name = Name('r', 'o', 'o', 't');
+const HUMN: Name = Name('h', 'u', 'm', 'n');
+// The answer when `root`'s numbers overflow or divide by 0.
+const NO_ROOT: &str = "root can't work out its number";
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Job {
+    Yell(isize),
+    Add(Name, Name),
+    Sub(Name, Name),
+    Mul(Name, Name),
+    Div(Name, Name),
+}
+impl fmt::Debug for Job {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Yell(value) => write!(f, "{}", value),
+            Self::Add(v1, v2) => write!(f, "{:?} + {:?}", v1, v2),
+            Self::Sub(v1, v2) => write!(f, "{:?} - {:?}", v1, v2),
+            Self::Mul(v1, v2) => write!(f, "{:?} * {:?}", v1, v2),
+            Self::Div(v1, v2) => write!(f, "{:?} / {:?}", v1, v2),
+        }
+    }
+}
+impl Job {
+    fn from_line(job: Line) -> Result<Job, ParseError> {
+        match job.words().collect::<Vec<Line>>()[..] {
+            [name1, op, name2] => {
+                let (s1, s2) = (Name::from_line(name1)?, Name::from_line(name2)?);
+                match op.text() {
+                    "+" => Ok(Job::Add(s1, s2)),
+                    "-" => Ok(Job::Sub(s1, s2)),
+                    "*" => Ok(Job::Mul(s1, s2)),
+                    "/" => Ok(Job::Div(s1, s2)),
+                    _ => Err(op.error("an operator: +, -, * or /")),
+                }
+            }
+            [value] => Ok(Job::Yell(value.parse::<isize>()?)),
+            _ => Err(job
+                .trim()
+                .error("a number or an operation like `abcd + efgh`")),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct MonkeyMath {
+    monkeys: BTreeMap<Name, Job>,
+}
+impl FromStr for MonkeyMath {
+    type Err = ParseError;
+    fn from_str(input_text: &str) -> Result<MonkeyMath, Self::Err> {
+        let jobs = parse::lines(input_text.trim_end())
+            .map(|line| {
+                let (name, job) = line.split_once(": ")?;
+                Ok((Name::from_line(name)?, Job::from_line(job)?, job))
+            })
+            .collect::<Result<Vec<(Name, Job, Line)>, ParseError>>()?;
+        let monkeys = jobs
+            .iter()
+            .map(|(name, job, _)| (*name, *job))
+            .collect::<BTreeMap<Name, Job>>();
+        for name in [ROOT, HUMN] {
+            if !monkeys.contains_key(&name) {
+                let expected = format!("a monkey named {:?}", name);
+                return Err(parse::first_line(input_text).error(expected));
+            }
+        }
+        for (_, _, job) in &jobs {
+            if let [a, _, b] = job.words().collect::<Vec<Line>>()[..] {
+                for operand in [a, b] {
+                    if !monkeys.contains_key(&Name::from_line(operand)?) {
+                        return Err(operand.error("a monkey described in the input"));
+                    }
+                }
+            }
+        }
+        let math = MonkeyMath { monkeys };
+        if let Some(name) = math.waiting_on_itself() {
+            let (_, _, job) = jobs.iter().find(|(n, _, _)| *n == name).unwrap();
+            let expected = format!("a job that doesn't need {:?}'s own number", name);
+            return Err(job.error(expected));
+        }
+        Ok(math)
+    }
+}
+
+impl MonkeyMath {
+    // A monkey that would wait forever for its own number, if any.
+    fn waiting_on_itself(&self) -> Option<Name> {
+        // false while a monkey's operands are being followed, true once they
+        // all turned out to yell.
+        fn follow(math: &MonkeyMath, name: Name, done: &mut BTreeMap<Name, bool>) -> Option<Name> {
+            match done.get(&name) {
+                Some(false) => return Some(name),
+                Some(true) => return None,
+                None => {}
+            }
+            done.insert(name, false);
+            if let (Some(a), Some(b)) = math.get_children(&name) {
+                if let Some(name) = follow(math, a, done).or_else(|| follow(math, b, done)) {
+                    return Some(name);
+                }
+            }
+            done.insert(name, true);
+            None
+        }
+        let mut done = BTreeMap::new();
+        self.monkeys
+            .keys()
+            .find_map(|name| follow(self, *name, &mut done))
+    }
+    fn get(&self, name: &Name) -> Option<Job> {
+        self.monkeys.get(name).copied()
+    }
+    // `None` if the numbers overflow or divide by 0.
+    fn calc(&self, name: &Name) -> Option<isize> {
+        match self.get(name)? {
+            Job::Yell(value) => Some(value),
+            Job::Add(name1, name2) => self.calc(&name1)?.checked_add(self.calc(&name2)?),
+            Job::Sub(name1, name2) => self.calc(&name1)?.checked_sub(self.calc(&name2)?),
+            Job::Mul(name1, name2) => self.calc(&name1)?.checked_mul(self.calc(&name2)?),
+            Job::Div(name1, name2) => self.calc(&name1)?.checked_div(self.calc(&name2)?),
+        }
+    }
+    fn in_chain(&self, name: &Name, chain: &Name) -> bool {
+        if chain == name {
+            return true;
+        }
+        match self.get(chain) {
+            Some(Job::Yell(_)) => false,
+            Some(Job::Add(name1, name2))
+            | Some(Job::Sub(name1, name2))
+            | Some(Job::Mul(name1, name2))
+            | Some(Job::Div(name1, name2)) => {
+                self.in_chain(name, &name1) || self.in_chain(name, &name2)
+            }
+            None => false,
+        }
+    }
+    // Inverting the operations on the way down to `humn`, e.g. with the example:
+    // Sub
+    // a)
+    // ptdq = 10
+    // humn = 12
+    // humn - ptdq
+    // dvpt = 2
+
+    // b)
+    // ptdq = 10
+    // dvpt = 2
+    // dvpt + ptdq
+    // humn = 12
+
+    // Div
+    // a)
+    // pppw = 10
+    // cchz = 20
+    // cchz/pppw
+    // lfqf = 2
+
+    // b)
+    // pppw = 10
+    // lfqf = 2
+    // lfqf * pppw
+    // cchz = 20
+    fn rev_calc(&self, unknown: &Name, chain: &Name, value: isize) -> Option<isize> {
+        if unknown == chain {
+            return Some(value);
+        }
+        let (Some(child1), Some(child2)) = self.get_children(chain) else {
+            return None;
+        };
+        let (unk_child, other_child) = if self.in_chain(unknown, &child1) {
+            (child1, child2)
+        } else {
+            (child2, child1)
+        };
+        let other_value = self.calc(&other_child)?;
+        let value = match (self.get(chain), child1 == unk_child) {
+            (Some(Job::Add(_, _)), _) => value.checked_sub(other_value),
+            (Some(Job::Sub(_, _)), true) => value.checked_add(other_value),
+            (Some(Job::Sub(_, _)), false) => other_value.checked_sub(value),
+            (Some(Job::Mul(_, _)), _) => value.checked_div(other_value),
+            (Some(Job::Div(_, _)), true) => value.checked_mul(other_value),
+            (Some(Job::Div(_, _)), false) => other_value.checked_div(value),
+            _ => None,
+        }?;
+        self.rev_calc(unknown, &unk_child, value)
+    }
+    fn get_children(&self, monkey: &Name) -> (Option<Name>, Option<Name>) {
+        match self.get(monkey) {
+            Some(Job::Add(child1, child2))
+            | Some(Job::Sub(child1, child2))
+            | Some(Job::Mul(child1, child2))
+            | Some(Job::Div(child1, child2)) => (Some(child1), Some(child2)),
+            _ => (None, None),
+        }
+    }
+}
+
+// Generated monkeys, see `MonkeyMathPuzzle::generate`.
+impl MonkeyMath {
+    fn new_name(&self, rng: &mut Rng) -> Name {
+        loop {
+            let mut letter = || (b'a' + rng.range(0..26) as u8) as char;
+            let name = Name(letter(), letter(), letter(), letter());
+            if name != ROOT && name != HUMN && !self.monkeys.contains_key(&name) {
+                return name;
+            }
+        }
+    }
+    // A new monkey yelling `value`, worked out by about `budget` monkeys.
+    fn yelling(&mut self, rng: &mut Rng, value: isize, budget: usize) -> Name {
+        let name = self.new_name(rng);
+        self.monkeys.insert(name, Job::Yell(value));
+        if budget <= 1 {
+            return name;
+        }
+        let left = rng.range(0..budget);
+        let right = budget - 1 - left;
+        let (c, d) = (rng.signed(1..100) as isize, rng.range(2..10) as isize);
+        let job = match rng.range(0..4) {
+            0 if value % d == 0 => Job::Mul(
+                self.yelling(rng, value / d, left),
+                self.yelling(rng, d, right),
+            ),
+            1 if value.abs() < 1_000_000 => Job::Div(
+                self.yelling(rng, value * d, left),
+                self.yelling(rng, d, right),
+            ),
+            2 => Job::Sub(
+                self.yelling(rng, value + c, left),
+                self.yelling(rng, c, right),
+            ),
+            _ => Job::Add(
+                self.yelling(rng, value - c, left),
+                self.yelling(rng, c, right),
+            ),
+        };
+        self.monkeys.insert(name, job);
+        name
+    }
+}
+
+// Every monkey yelling as soon as both of its numbers are known, the way the
+// puzzle tells it, with `humn` yelling `humn` if given. With `exact`, a
+// division with a remainder is as good as no number at all.
+fn yell_all(math: &MonkeyMath, humn: Option<isize>, exact: bool) -> BTreeMap<Name, isize> {
+    let mut yelled = BTreeMap::<Name, isize>::new();
+    loop {
+        let before = yelled.len();
+        for (name, job) in &math.monkeys {
+            if yelled.contains_key(name) {
+                continue;
+            }
+            let value = match job {
+                _ if *name == HUMN && humn.is_some() => humn,
+                Job::Yell(value) => Some(*value),
+                Job::Add(a, b) | Job::Sub(a, b) | Job::Mul(a, b) | Job::Div(a, b) => {
+                    match (yelled.get(a), yelled.get(b)) {
+                        (Some(&a), Some(&b)) => match job {
+                            Job::Add(..) => a.checked_add(b),
+                            Job::Sub(..) => a.checked_sub(b),
+                            Job::Mul(..) => a.checked_mul(b),
+                            _ if exact && b != 0 && a % b != 0 => None,
+                            _ => a.checked_div(b),
+                        },
+                        _ => None,
+                    }
+                }
+            };
+            if let Some(value) = value {
+                yelled.insert(*name, value);
+            }
+        }
+        if yelled.len() == before {
+            return yelled;
+        }
+    }
+}
+
+struct MonkeyMathPuzzle;
+impl Solution for MonkeyMathPuzzle {
+    type Parsed = MonkeyMath;
+
+    fn parse(input_text: &str) -> Result<Self::Parsed, ParseError> {
+        input_text.parse::<MonkeyMath>()
+    }
+    fn part1(math: &Self::Parsed) -> Answer {
+        math.calc(&ROOT).map_or(NO_ROOT.into(), Answer::from)
+    }
+    fn part2(math: &Self::Parsed) -> Answer {
+        let (Some(child1), Some(child2)) = math.get_children(&ROOT) else {
+            return "root doesn't compare".into();
+        };
+        let (humn_child, other_child) = if math.in_chain(&HUMN, &child1) {
+            (child1, child2)
+        } else {
+            (child2, child1)
+        };
+        math.calc(&other_child)
+            .and_then(|value| math.rev_calc(&HUMN, &humn_child, value))
+            .map_or("no number for humn".into(), Answer::from)
+    }
+}
+
+impl Reference for MonkeyMathPuzzle {
+    fn reference_part1(math: &Self::Parsed) -> Answer {
+        yell_all(math, None, false)
+            .get(&ROOT)
+            .map_or(NO_ROOT.into(), |root| (*root).into())
+    }
+    // Tries every number for `humn`, nearest to 0 first. Generated inputs
+    // have small answers; real ones would take ages.
+    fn reference_part2(math: &Self::Parsed) -> Answer {
+        let (a, b) = match math.get_children(&ROOT) {
+            (Some(a), Some(b)) => (a, b),
+            _ => return "root doesn't compare".into(),
+        };
+        (0..=100_000_isize)
+            .flat_map(|humn| [humn, -humn])
+            .find(|humn| {
+                let yelled = yell_all(math, Some(*humn), true);
+                yelled.contains_key(&a) && yelled.get(&a) == yelled.get(&b)
+            })
+            .map_or("no humn up to 100000".into(), |humn| humn.into())
+    }
+}
+
+impl Generate for MonkeyMathPuzzle {
+    // `humn` at the bottom of a chain of monkeys up to `root`, built upwards
+    // from the number `humn` should yell. Divisions come out even with that
+    // number, so part two has an exact answer; `humn` starts out yelling
+    // something else.
+    fn generate(rng: &mut Rng, size: usize) -> Self::Parsed {
+        let mut math = MonkeyMath {
+            monkeys: BTreeMap::new(),
+        };
+        math.monkeys
+            .insert(HUMN, Job::Yell(rng.signed(1..1000) as isize));
+        let (mut chain, mut value) = (HUMN, rng.signed(1..1000) as isize);
+        let mut budget = size / 2;
+        while budget > 0 {
+            let spent = rng.range(1..budget.min(6) + 1);
+            budget -= spent;
+            let name = math.new_name(rng);
+            math.monkeys.insert(name, Job::Yell(0));
+            let (c, d) = (rng.signed(1..100) as isize, rng.range(2..10) as isize);
+            let (job, next) = match rng.range(0..6) {
+                0 => (Job::Add(chain, math.yelling(rng, c, spent)), value + c),
+                1 => (Job::Add(math.yelling(rng, c, spent), chain), c + value),
+                2 => (Job::Sub(chain, math.yelling(rng, c, spent)), value - c),
+                3 => (Job::Sub(math.yelling(rng, c, spent), chain), c - value),
+                4 if value.abs() < 1_000_000 => {
+                    (Job::Mul(chain, math.yelling(rng, d, spent)), value * d)
+                }
+                _ if value % d == 0 => (Job::Div(chain, math.yelling(rng, d, spent)), value / d),
+                _ => (Job::Add(chain, math.yelling(rng, c, spent)), value + c),
+            };
+            math.monkeys.insert(name, job);
+            (chain, value) = (name, next);
+        }
+        let other = math.yelling(rng, value, size - size / 2);
+        let job = match rng.chance(50) {
+            true => Job::Add(chain, other),
+            false => Job::Add(other, chain),
+        };
+        math.monkeys.insert(ROOT, job);
+        math
+    }
+    fn render(math: &Self::Parsed) -> String {
+        math.monkeys
+            .iter()
+            .map(|(name, job)| format!("{:?}: {:?}", name, job))
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+}
+
+pub const DAY: Day =
+    Day::new::<MonkeyMathPuzzle>(21, "Monkey Math").with_reference::<MonkeyMathPuzzle>();
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_monkeys_that_never_yell() {
+        let missing = "root: abcd + humn\nhumn: 5";
+        let err = parse_error(missing);
+        assert!(err.contains("line 1, column 7"), "{}", err);
+        let circular = "root: abcd + humn\nhumn: 5\nabcd: efgh * humn\nefgh: abcd - humn";
+        let err = parse_error(circular);
+        assert!(err.contains("own number"), "{}", err);
+    }
+
+    fn parse_error(input_text: &str) -> String {
+        input_text.parse::<MonkeyMath>().unwrap_err().to_string()
+    }
+}
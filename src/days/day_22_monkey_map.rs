@@ -0,0 +1,480 @@
+// Advent Of Code
+// https://adventofcode.com/2022/day/22
+//
+// --- Day 22: Monkey Map ---
+// Puzzle description: puzzles/day_22.md
+
+use crate::days::Day;
+use crate::generate::{Generate, Rng};
+use crate::geom::{Direction4, Point2, Point3};
+use crate::grid::Grid;
+use crate::parse::{self, Line, ParseError};
+use crate::{Answer, Solution};
+use std::fmt;
+
+type Coord = Point2<usize>;
+type Vector = Point3<i32>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Tile {
+    // Off the map: rows are ragged and some start with spaces.
+    Void,
+    Open,
+    Wall,
+}
+impl fmt::Display for Tile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Tile::Void => write!(f, " "),
+            Tile::Open => write!(f, "."),
+            Tile::Wall => write!(f, "#"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Step {
+    Forward(usize),
+    Left,
+    Right,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Notes {
+    board: Grid<Tile>,
+    path: Vec<Step>,
+}
+
+fn parse_board(lines: &[Line]) -> Result<Grid<Tile>, ParseError> {
+    let width = lines
+        .iter()
+        .map(|line| line.text().len())
+        .max()
+        .unwrap_or(0);
+    let mut board = Grid::new(width, 0, Tile::Void);
+    for line in lines {
+        let mut row = line
+            .text()
+            .char_indices()
+            .map(|(i, c)| match c {
+                ' ' => Ok(Tile::Void),
+                '.' => Ok(Tile::Open),
+                '#' => Ok(Tile::Wall),
+                _ => Err(line.skip(i).error("a tile: `.`, `#` or a space")),
+            })
+            .collect::<Result<Vec<Tile>, ParseError>>()?;
+        row.resize(width, Tile::Void);
+        board.push_row(row);
+    }
+    Ok(board)
+}
+
+fn parse_path(line: Line) -> Result<Vec<Step>, ParseError> {
+    let mut path = vec![];
+    let mut rest = line;
+    loop {
+        let (forward, after) = rest.take_int()?;
+        path.push(Step::Forward(forward));
+        rest = after;
+        if rest.is_empty() {
+            return Ok(path);
+        }
+        path.push(match rest.text().as_bytes()[0] {
+            b'L' => Step::Left,
+            b'R' => Step::Right,
+            _ => return Err(rest.error("a turn, `L` or `R`")),
+        });
+        rest = rest.skip(1);
+    }
+}
+
+fn parse_input(input_text: &str) -> Result<Notes, ParseError> {
+    let lines = parse::lines(input_text.trim_end()).collect::<Vec<Line>>();
+    let blank = lines
+        .iter()
+        .position(|line| line.text().trim().is_empty())
+        .ok_or_else(|| parse::first_line(input_text).error("a map, a blank line and a path"))?;
+    let (map, path) = (&lines[..blank], &lines[blank + 1..]);
+    if map.is_empty() {
+        return Err(lines[0].error("a map before the blank line"));
+    }
+    let board = parse_board(map)?;
+    if !board.row(0).contains(&Tile::Open) {
+        return Err(lines[0].error("an open tile on the top row to start from"));
+    }
+    let path = match path {
+        [path] => parse_path(*path)?,
+        [] => return Err(lines[blank].error("a path after the blank line")),
+        [_, extra, ..] => return Err(extra.error("the end of the input after the path")),
+    };
+    Ok(Notes { board, path })
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Walker {
+    position: Coord,
+    facing: Direction4,
+}
+impl Walker {
+    fn password(&self) -> usize {
+        let facing = match self.facing {
+            Direction4::Right => 0,
+            Direction4::Down => 1,
+            Direction4::Left => 2,
+            Direction4::Up => 3,
+        };
+        1000 * (self.position.y + 1) + 4 * (self.position.x + 1) + facing
+    }
+}
+
+fn on_map(board: &Grid<Tile>, position: Option<Coord>) -> Option<Coord> {
+    position.filter(|p| board.get(p.x, p.y).is_some_and(|tile| *tile != Tile::Void))
+}
+
+// Walks the path from the leftmost open tile of the top row. `wrap` says where
+// a walker stepping off the map comes back on, and facing where.
+fn walk(notes: &Notes, wrap: impl Fn(&Grid<Tile>, Walker) -> Walker) -> Walker {
+    let board = &notes.board;
+    let start = board.row(0).iter().position(|tile| *tile == Tile::Open);
+    let mut walker = Walker {
+        position: Coord::new(start.expect("Checked when parsing"), 0),
+        facing: Direction4::Right,
+    };
+    for step in &notes.path {
+        match step {
+            Step::Left => walker.facing = walker.facing.turn_left(),
+            Step::Right => walker.facing = walker.facing.turn_right(),
+            Step::Forward(count) => {
+                for _ in 0..*count {
+                    let next = match on_map(board, walker.position.step(walker.facing)) {
+                        Some(position) => Walker { position, ..walker },
+                        None => wrap(board, walker),
+                    };
+                    if board[next.position] == Tile::Wall {
+                        break;
+                    }
+                    walker = next;
+                }
+            }
+        }
+    }
+    walker
+}
+
+// Off one edge and back on the opposite edge of the same row or column.
+fn wrap_flat(board: &Grid<Tile>, walker: Walker) -> Walker {
+    let back = walker.facing.opposite();
+    let mut position = walker.position;
+    while let Some(previous) = on_map(board, position.step(back)) {
+        position = previous;
+    }
+    Walker { position, ..walker }
+}
+
+// A face of the folded cube: where it is on the map and which way its sides
+// point once folded, as unit vectors.
+#[derive(Debug, Clone, Copy)]
+struct Face {
+    corner: Coord,
+    normal: Vector,
+    right: Vector,
+    down: Vector,
+}
+impl Face {
+    // Where stepping off the face in `direction` leads, which is the normal of
+    // the face on the other side of that edge.
+    fn towards(&self, direction: Direction4) -> Vector {
+        match direction {
+            Direction4::Up => -self.down,
+            Direction4::Right => self.right,
+            Direction4::Down => self.down,
+            Direction4::Left => -self.right,
+        }
+    }
+    // This face seen from the one next to it in `direction`, rolled over the
+    // edge between them.
+    fn roll(&self, direction: Direction4) -> Self {
+        let Self {
+            normal,
+            right,
+            down,
+            ..
+        } = *self;
+        let (normal, right, down) = match direction {
+            Direction4::Up => (-down, right, normal),
+            Direction4::Right => (right, -normal, down),
+            Direction4::Down => (down, right, -normal),
+            Direction4::Left => (-right, normal, down),
+        };
+        Self {
+            normal,
+            right,
+            down,
+            ..*self
+        }
+    }
+}
+
+// The map folded into a cube, with each face knowing which way it points, so
+// that the faces on either side of any edge can be found.
+#[derive(Debug, Clone)]
+struct Cube {
+    side: usize,
+    faces: Vec<Face>,
+}
+impl Cube {
+    // `None` unless the map is six square faces of the same size, all in one
+    // piece.
+    fn fold(board: &Grid<Tile>) -> Option<Self> {
+        let tiles = board
+            .iter()
+            .filter(|(_, tile)| **tile != Tile::Void)
+            .count();
+        let side = (1..=tiles).find(|side| 6 * side * side >= tiles)?;
+        if 6 * side * side != tiles {
+            return None;
+        }
+        // The top left corner of a face, if there's one at `x`, `y`.
+        let at = |x: usize, y: usize| {
+            let mut face = (x..x + side).flat_map(|x| (y..y + side).map(move |y| (x, y)));
+            let full = x.is_multiple_of(side)
+                && y.is_multiple_of(side)
+                && face.all(|(x, y)| board.get(x, y).is_some_and(|tile| *tile != Tile::Void));
+            full.then_some(Coord::new(x, y))
+        };
+        let corners = board
+            .positions()
+            .filter_map(|(x, y)| at(x, y))
+            .collect::<Vec<Coord>>();
+        if corners.len() != 6 {
+            return None;
+        }
+        // Lay the first face flat and roll the others into place from it.
+        let mut faces = vec![Face {
+            corner: corners[0],
+            normal: Vector::new(0, 0, 1),
+            right: Vector::new(1, 0, 0),
+            down: Vector::new(0, 1, 0),
+        }];
+        let mut next = 0;
+        while next < faces.len() {
+            let face = faces[next];
+            for direction in Direction4::ALL {
+                let (dx, dy) = direction.delta();
+                let corner = face
+                    .corner
+                    .x
+                    .checked_add_signed(dx * side as isize)
+                    .zip(face.corner.y.checked_add_signed(dy * side as isize))
+                    .filter(|(x, y)| board.contains(*x, *y))
+                    .and_then(|(x, y)| at(x, y));
+                let Some(corner) = corner else {
+                    continue;
+                };
+                if faces.iter().all(|face| face.corner != corner) {
+                    faces.push(Face {
+                        corner,
+                        ..face.roll(direction)
+                    });
+                }
+            }
+            next += 1;
+        }
+        let mut normals = faces.iter().map(|face| face.normal).collect::<Vec<_>>();
+        normals.sort();
+        normals.dedup();
+        (faces.len() == 6 && normals.len() == 6).then_some(Self { side, faces })
+    }
+
+    fn face(&self, position: Coord) -> &Face {
+        self.faces
+            .iter()
+            .find(|face| {
+                (face.corner.x..face.corner.x + self.side).contains(&position.x)
+                    && (face.corner.y..face.corner.y + self.side).contains(&position.y)
+            })
+            .expect("Every tile is on a face")
+    }
+
+    // Off the edge of one face and onto the face it's folded against. Going
+    // clockwise round their own face, both see the edge they share the other
+    // way round, which is how a tile's offset along it carries over.
+    fn wrap(&self, walker: Walker) -> Walker {
+        let last = self.side - 1;
+        let from = self.face(walker.position);
+        let x = walker.position.x - from.corner.x;
+        let y = walker.position.y - from.corner.y;
+        let offset = match walker.facing {
+            Direction4::Up => x,
+            Direction4::Right => y,
+            Direction4::Down => last - x,
+            Direction4::Left => last - y,
+        };
+        let normal = from.towards(walker.facing);
+        let to = self
+            .faces
+            .iter()
+            .find(|face| face.normal == normal)
+            .expect("Six faces, one each way");
+        let edge = Direction4::ALL
+            .into_iter()
+            .find(|direction| to.towards(*direction) == from.normal)
+            .expect("Neighbours point at each other");
+        let offset = last - offset;
+        let (x, y) = match edge {
+            Direction4::Up => (offset, 0),
+            Direction4::Right => (last, offset),
+            Direction4::Down => (last - offset, last),
+            Direction4::Left => (0, last - offset),
+        };
+        Walker {
+            position: Coord::new(to.corner.x + x, to.corner.y + y),
+            facing: edge.opposite(),
+        }
+    }
+}
+
+// Any map will do for part one, only nets of a cube for part two.
+const NOT_A_CUBE: &str = "the map doesn't fold into a cube";
+
+struct MonkeyMap;
+impl Solution for MonkeyMap {
+    type Parsed = Notes;
+
+    fn parse(input_text: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input_text)
+    }
+    fn part1(notes: &Self::Parsed) -> Answer {
+        walk(notes, wrap_flat).password().into()
+    }
+    fn part2(notes: &Self::Parsed) -> Answer {
+        match Cube::fold(&notes.board) {
+            Some(cube) => walk(notes, |_, walker| cube.wrap(walker)).password().into(),
+            None => NOT_A_CUBE.into(),
+        }
+    }
+}
+
+// Cube nets, in faces: the example's, the one real inputs use, then the
+// eleven shapes every other net is a turn or a flip of.
+const NETS: [[&str; 4]; 13] = [
+    ["..#.", "###.", "..##", ""],
+    [".##.", ".#..", "##..", "#..."],
+    ["#...", "####", "#...", ""],
+    ["#...", "####", ".#..", ""],
+    ["#...", "####", "..#.", ""],
+    ["#...", "####", "...#", ""],
+    [".#..", "####", ".#..", ""],
+    [".#..", "####", "..#.", ""],
+    ["##..", ".###", ".#..", ""],
+    ["##..", ".###", "..#.", ""],
+    ["##..", ".###", "...#", ""],
+    ["##..", ".##.", "..##", ""],
+    ["###..", "..###", "", ""],
+];
+
+// Lays out `net` with faces `side` tiles wide, asking `tile` for each of their
+// tiles.
+fn unfold(net: &[&str], side: usize, mut tile: impl FnMut() -> Tile) -> Grid<Tile> {
+    let faces = net
+        .iter()
+        .map(|faces| faces.trim_end_matches('.'))
+        .filter(|faces| !faces.is_empty())
+        .collect::<Vec<&str>>();
+    let width = faces.iter().map(|faces| faces.len()).max().unwrap() * side;
+    let mut board = Grid::new(width, 0, Tile::Void);
+    for faces in faces {
+        for _ in 0..side {
+            let mut row = faces
+                .chars()
+                .flat_map(|face| (0..side).map(move |_| face))
+                .map(|face| match face {
+                    '#' => tile(),
+                    _ => Tile::Void,
+                })
+                .collect::<Vec<Tile>>();
+            row.resize(width, Tile::Void);
+            board.push_row(row);
+        }
+    }
+    board
+}
+
+impl Generate for MonkeyMap {
+    // A net of faces `size` tiles wide, with a few walls, and a path.
+    fn generate(rng: &mut Rng, size: usize) -> Self::Parsed {
+        let side = size.max(2);
+        let net = *rng.pick(&NETS);
+        let mut board = unfold(&net, side, || match rng.chance(10) {
+            true => Tile::Wall,
+            false => Tile::Open,
+        });
+        // The path starts on the leftmost open tile of the top row.
+        let start = board.row(0).iter().position(|tile| *tile != Tile::Void);
+        board[(start.unwrap(), 0)] = Tile::Open;
+        let mut path = vec![Step::Forward(rng.range(1..side * 2))];
+        for _ in 0..size.max(2) {
+            path.push(*rng.pick(&[Step::Left, Step::Right]));
+            path.push(Step::Forward(rng.range(1..side * 2)));
+        }
+        Notes { board, path }
+    }
+    fn render(notes: &Self::Parsed) -> String {
+        let rows = notes
+            .board
+            .rows()
+            .map(|row| {
+                let row = row.iter().map(|tile| tile.to_string()).collect::<String>();
+                row.trim_end().to_string()
+            })
+            .collect::<Vec<String>>();
+        let path = notes
+            .path
+            .iter()
+            .map(|step| match step {
+                Step::Forward(count) => count.to_string(),
+                Step::Left => "L".to_string(),
+                Step::Right => "R".to_string(),
+            })
+            .collect::<String>();
+        format!("{}\n\n{}", rows.join("\n"), path)
+    }
+}
+
+pub const DAY: Day = Day::new::<MonkeyMap>(22, "Monkey Map");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_net_folds_and_its_edges_lead_back() {
+        for net in NETS {
+            let board = unfold(&net, 3, || Tile::Open);
+            let cube = Cube::fold(&board).expect("Nets fold");
+            for ((x, y), tile) in board.iter() {
+                for facing in Direction4::ALL {
+                    let position = Coord::new(x, y);
+                    if *tile == Tile::Void || on_map(&board, position.step(facing)).is_some() {
+                        continue;
+                    }
+                    let there = cube.wrap(Walker { position, facing });
+                    let back = cube.wrap(Walker {
+                        facing: there.facing.opposite(),
+                        ..there
+                    });
+                    assert_eq!((back.position, back.facing), (position, facing.opposite()));
+                }
+            }
+        }
+        let strip = unfold(&["######"], 3, || Tile::Open);
+        assert!(Cube::fold(&strip).is_none());
+    }
+
+    #[test]
+    fn flat_maps_only_walk_in_part_one() {
+        let notes = MonkeyMap::parse("...#\n.#..\n\n3R2").unwrap();
+        assert_eq!(MonkeyMap::part1(&notes), Answer::from(1013_usize));
+        assert_eq!(MonkeyMap::part2(&notes), Answer::from(NOT_A_CUBE));
+    }
+}
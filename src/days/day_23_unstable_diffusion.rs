@@ -0,0 +1,140 @@
+// Advent Of Code
+// https://adventofcode.com/2022/day/23
+//
+// --- Day 23: Unstable Diffusion ---
+// Puzzle description: puzzles/day_23.md
+
+use crate::days::Day;
+use crate::generate::{Generate, Rng};
+use crate::geom::{Direction8, Point2};
+use crate::grid::Grid;
+use crate::parse::{self, ParseError};
+use crate::{Answer, Cancel, Cancelled, Solution};
+use std::collections::{HashMap, HashSet};
+
+type Coord = Point2<i32>;
+
+// The directions elves look at, in the order of the first round.
+const PROPOSALS: [Direction8; 4] = [Direction8::N, Direction8::S, Direction8::W, Direction8::E];
+
+// Elves spread out over an unbounded ground, so only where they are is kept.
+#[derive(Debug, Clone)]
+struct Grove {
+    elves: HashSet<Coord>,
+    rounds: usize,
+}
+impl Grove {
+    fn new(scan: &Grid<bool>) -> Self {
+        let elves = scan
+            .iter()
+            .filter(|(_, elf)| **elf)
+            .map(|((x, y), _)| Coord::new(x as i32, y as i32))
+            .collect();
+        Self { elves, rounds: 0 }
+    }
+
+    // Where the elf at `elf` wants to go this round, if anywhere.
+    fn proposal(&self, elf: Coord) -> Option<Coord> {
+        let free = |direction: Direction8| !self.elves.contains(&(elf + direction));
+        if Direction8::ALL.into_iter().all(free) {
+            return None;
+        }
+        (0..PROPOSALS.len())
+            .map(|i| PROPOSALS[(self.rounds + i) % PROPOSALS.len()])
+            .find(|d| free(d.turn_left()) && free(*d) && free(d.turn_right()))
+            .map(|direction| elf + direction)
+    }
+
+    // Plays a round and tells whether any elf moved. Elves proposing the same
+    // tile all stay where they are.
+    fn round(&mut self) -> bool {
+        let proposals = self
+            .elves
+            .iter()
+            .filter_map(|elf| Some((*elf, self.proposal(*elf)?)))
+            .collect::<Vec<(Coord, Coord)>>();
+        let mut wanted = HashMap::<Coord, usize>::new();
+        for (_, to) in &proposals {
+            *wanted.entry(*to).or_default() += 1;
+        }
+        let mut moved = false;
+        for (from, to) in proposals {
+            if wanted[&to] == 1 {
+                self.elves.remove(&from);
+                self.elves.insert(to);
+                moved = true;
+            }
+        }
+        self.rounds += 1;
+        moved
+    }
+
+    // Tiles of the smallest rectangle around the elves that no elf is on.
+    fn empty_ground(&self) -> usize {
+        let xs = self.elves.iter().map(|elf| elf.x);
+        let ys = self.elves.iter().map(|elf| elf.y);
+        let (Some(x_min), Some(x_max)) = (xs.clone().min(), xs.max()) else {
+            return 0;
+        };
+        let (y_min, y_max) = (ys.clone().min().unwrap(), ys.max().unwrap());
+        let area = (x_max - x_min + 1) as usize * (y_max - y_min + 1) as usize;
+        area - self.elves.len()
+    }
+}
+
+fn parse_input(input_text: &str) -> Result<Grid<bool>, ParseError> {
+    parse::grid(input_text, "an elf `#` or ground `.`", |c| match c {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    })
+}
+
+struct UnstableDiffusion;
+impl Solution for UnstableDiffusion {
+    type Parsed = Grid<bool>;
+
+    fn parse(input_text: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input_text)
+    }
+    fn part1(scan: &Self::Parsed) -> Answer {
+        const ROUNDS: usize = 10;
+        let mut grove = Grove::new(scan);
+        for _ in 0..ROUNDS {
+            grove.round();
+        }
+        grove.empty_ground().into()
+    }
+    fn part2(scan: &Self::Parsed) -> Answer {
+        Self::part2_cancellable(scan, &Cancel::default()).expect("Never cancelled")
+    }
+    fn part2_cancellable(scan: &Self::Parsed, cancel: &Cancel) -> Result<Answer, Cancelled> {
+        let mut grove = Grove::new(scan);
+        while grove.round() {
+            if cancel.is_cancelled() {
+                return Err(Cancelled {
+                    progress: Some(format!("still moving after {} rounds", grove.rounds)),
+                });
+            }
+        }
+        Ok(grove.rounds.into())
+    }
+}
+
+impl Generate for UnstableDiffusion {
+    // A square scan with elves on about 40% of it, at least one in the middle.
+    fn generate(rng: &mut Rng, size: usize) -> Self::Parsed {
+        let side = size.max(1);
+        let mut scan = Grid::new(side, side, false);
+        for (x, y) in scan.positions() {
+            scan[(x, y)] = rng.chance(40);
+        }
+        scan[(side / 2, side / 2)] = true;
+        scan
+    }
+    fn render(scan: &Self::Parsed) -> String {
+        scan.render(|elf| if *elf { '#' } else { '.' })
+    }
+}
+
+pub const DAY: Day = Day::new::<UnstableDiffusion>(23, "Unstable Diffusion");
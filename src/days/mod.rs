@@ -0,0 +1,103 @@
+// Puzzle solutions, one module per day
+
+pub mod day_01_calorie_counting;
+pub mod day_02_rock_paper_scissors;
+pub mod day_03_rucksack_reorganization;
+pub mod day_04_camp_cleanup;
+pub mod day_05_supply_stacks;
+pub mod day_06_tuning_trouble;
+pub mod day_07_no_space_left_on_device;
+pub mod day_08_treetop_tree_house;
+pub mod day_09_rope_bridge;
+pub mod day_10_cathode_ray_tube;
+pub mod day_11_monkey_in_the_middle;
+pub mod day_12_hill_climbing_algorithm;
+pub mod day_13_distress_signal;
+pub mod day_14_regolith_resevoir;
+pub mod day_15_beacon_exclusion_zone;
+pub mod day_16_proboscidea_volcanium;
+pub mod day_17_pyroplastic_flow;
+pub mod day_18_boiling_boulders;
+pub mod day_19_not_enough_minerals;
+pub mod day_20_grove_positioning_system;
+pub mod day_21_monkey_math;
+pub mod day_22_monkey_map;
+pub mod day_23_unstable_diffusion;
+
+use crate::fuzz;
+use crate::generate::{self, Generate, Rng, RoundTripError};
+use crate::reference::{self, Compare, Reference};
+use crate::{Cancel, ParseError, Solved};
+
+pub struct Day {
+    pub day: u8,
+    pub title: &'static str,
+    pub solve: fn(&str, &[u8], &Cancel) -> Result<Solved, ParseError>,
+    pub parse: fn(&str) -> Result<(), ParseError>,
+    pub generate: fn(&mut Rng, usize) -> String,
+    pub round_trip: fn(&mut Rng, usize) -> Result<(), RoundTripError>,
+    pub compare: Option<Compare>,
+}
+
+impl Day {
+    pub const fn new<S: Generate>(day: u8, title: &'static str) -> Self {
+        Self {
+            day,
+            title,
+            solve: S::solve,
+            parse: fuzz::parses::<S>,
+            generate: generate::generate::<S>,
+            round_trip: generate::round_trip::<S>,
+            compare: None,
+        }
+    }
+    // For days with a reference solution to check the fast one against.
+    pub const fn with_reference<S: Reference>(self) -> Self {
+        Self {
+            compare: Some(reference::compare::<S>),
+            ..self
+        }
+    }
+    // Solves `parts`, tagging parse errors with the day.
+    pub fn run(&self, input_text: &str, parts: &[u8]) -> Result<Solved, ParseError> {
+        self.run_cancellable(input_text, parts, &Cancel::default())
+    }
+    pub fn run_cancellable(
+        &self,
+        input_text: &str,
+        parts: &[u8],
+        cancel: &Cancel,
+    ) -> Result<Solved, ParseError> {
+        (self.solve)(input_text, parts, cancel).map_err(|err| err.with_day(self.day))
+    }
+}
+
+pub static DAYS: [Day; 23] = [
+    day_01_calorie_counting::DAY,
+    day_02_rock_paper_scissors::DAY,
+    day_03_rucksack_reorganization::DAY,
+    day_04_camp_cleanup::DAY,
+    day_05_supply_stacks::DAY,
+    day_06_tuning_trouble::DAY,
+    day_07_no_space_left_on_device::DAY,
+    day_08_treetop_tree_house::DAY,
+    day_09_rope_bridge::DAY,
+    day_10_cathode_ray_tube::DAY,
+    day_11_monkey_in_the_middle::DAY,
+    day_12_hill_climbing_algorithm::DAY,
+    day_13_distress_signal::DAY,
+    day_14_regolith_resevoir::DAY,
+    day_15_beacon_exclusion_zone::DAY,
+    day_16_proboscidea_volcanium::DAY,
+    day_17_pyroplastic_flow::DAY,
+    day_18_boiling_boulders::DAY,
+    day_19_not_enough_minerals::DAY,
+    day_20_grove_positioning_system::DAY,
+    day_21_monkey_math::DAY,
+    day_22_monkey_map::DAY,
+    day_23_unstable_diffusion::DAY,
+];
+
+pub fn find_day(day: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.day == day)
+}
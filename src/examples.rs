@@ -0,0 +1,175 @@
+// Puzzle examples
+//
+// Examples live next to the inputs as `data/day_NN_example_K.txt`. The expected
+// answers go in a sidecar `data/day_NN_example_K.answers`, one `part: answer`
+// per line:
+//
+//   1: 24000
+//   2: 45000
+//
+// Multi-line answers (like day 10's screen) leave the value empty and follow
+// with the drawing on the next lines. Examples without a sidecar are skipped,
+// and so are parts whose answer is still empty, like the `1: ` and `2: `
+// placeholders `aoc new` writes.
+
+use crate::days::find_day;
+use crate::input::{data_dir, load_input, InputError};
+use std::error::Error;
+use std::fs::read_dir;
+use std::path::PathBuf;
+
+#[derive(Debug, Clone)]
+pub struct Example {
+    pub day: u8,
+    pub index: u8,
+    pub answers: Vec<(u8, String)>,
+}
+impl Example {
+    pub fn load(day: u8, index: u8) -> Result<Self, InputError> {
+        let path = answers_path(day, index);
+        let answers = parse_answers(&load_input(&path)?)
+            .map_err(|reason| InputError::Malformed(path, reason))?;
+        Ok(Self {
+            day,
+            index,
+            answers,
+        })
+    }
+    pub fn input(&self) -> Result<String, InputError> {
+        load_example(self.day, self.index)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Mismatch {
+    pub part: u8,
+    pub expected: String,
+    pub found: String,
+}
+
+pub fn example_path(day: u8, index: u8) -> PathBuf {
+    data_dir().join(format!("day_{:02}_example_{}.txt", day, index))
+}
+
+pub fn answers_path(day: u8, index: u8) -> PathBuf {
+    data_dir().join(format!("day_{:02}_example_{}.answers", day, index))
+}
+
+pub fn load_example(day: u8, index: u8) -> Result<String, InputError> {
+    load_input(example_path(day, index))
+}
+
+// Every example of `day` that has a sidecar with expected answers.
+pub fn find_examples(day: u8) -> Result<Vec<Example>, InputError> {
+    let dir = data_dir();
+    let prefix = format!("day_{:02}_example_", day);
+    let mut indexes = read_dir(&dir)
+        .map_err(|err| InputError::Io(dir.clone(), err))?
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| {
+            let name = entry.file_name().into_string().ok()?;
+            name.strip_prefix(&prefix)?
+                .strip_suffix(".answers")?
+                .parse::<u8>()
+                .ok()
+        })
+        .collect::<Vec<u8>>();
+    indexes.sort();
+    indexes
+        .into_iter()
+        .map(|index| Example::load(day, index))
+        .collect()
+}
+
+pub fn parse_answers(text: &str) -> Result<Vec<(u8, String)>, String> {
+    let mut answers: Vec<(u8, String)> = vec![];
+    for line in text.lines() {
+        let header = line
+            .split_once(':')
+            .and_then(|(part, value)| Some((part.trim().parse::<u8>().ok()?, value.trim())));
+        match (header, answers.last_mut()) {
+            (Some((part, value)), _) => answers.push((part, value.to_string())),
+            (None, Some((_, art))) => {
+                if !art.is_empty() {
+                    art.push('\n');
+                }
+                art.push_str(line);
+            }
+            (None, None) => return Err(format!("Expected `part: answer`, found `{line}`")),
+        }
+    }
+    Ok(answers)
+}
+
+// Runs the example through its day and compares every expected answer. An
+// example with only placeholders isn't run at all, it may not be written yet.
+pub fn check_example(example: &Example) -> Result<Vec<Mismatch>, Box<dyn Error>> {
+    let answers = example
+        .answers
+        .iter()
+        .filter(|(_, expected)| !expected.is_empty())
+        .collect::<Vec<_>>();
+    if answers.is_empty() {
+        return Ok(vec![]);
+    }
+    let day = find_day(example.day).expect("Examples belong to registered days");
+    let input_text = example.input()?;
+    let parts = answers.iter().map(|(part, _)| *part).collect::<Vec<u8>>();
+    let solved = day.run(&input_text, &parts)?;
+    Ok(answers
+        .into_iter()
+        .zip(solved.parts)
+        .map(|((part, expected), found)| Mismatch {
+            part: *part,
+            expected: expected.clone(),
+            found: match found.answer {
+                Ok(answer) => answer.to_string(),
+                Err(cancelled) => cancelled.to_string(),
+            },
+        })
+        .filter(|mismatch| mismatch.found != mismatch.expected)
+        .collect())
+}
+
+// Entry point for the generated example tests in `tests/examples.rs`.
+pub fn assert_example(day: u8, index: u8) {
+    let example = Example::load(day, index).unwrap_or_else(|err| panic!("{err}"));
+    let mismatches = check_example(&example).unwrap_or_else(|err| panic!("{err}"));
+    if !mismatches.is_empty() {
+        let report = mismatches
+            .iter()
+            .map(|m| {
+                format!(
+                    "Day {:02} example {} part {}:\nexpected: {}\n   found: {}",
+                    day, index, m.part, m.expected, m.found
+                )
+            })
+            .collect::<Vec<String>>()
+            .join("\n");
+        panic!("{report}");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_answers_with_art() {
+        let answers = parse_answers("1: 13140\n2:\n##..\n.##.\n").unwrap();
+        assert_eq!(
+            answers,
+            vec![(1, "13140".to_string()), (2, "##..\n.##.".to_string())]
+        );
+    }
+
+    #[test]
+    fn finds_numbered_examples() {
+        let indexes = find_examples(6)
+            .unwrap()
+            .iter()
+            .map(|e| e.index)
+            .collect::<Vec<u8>>();
+        assert_eq!(indexes, vec![1, 2, 3, 4, 5]);
+    }
+}
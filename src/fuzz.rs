@@ -0,0 +1,189 @@
+// Parser fuzzing
+//
+// Mangled generated inputs (see `generate`) may be rejected, but must not
+// make a parser panic. `aoc fuzz <days>` saves the inputs that did as
+// `data/fuzz/day_NN_<hash>.txt`, and `tests/fuzz.rs` replays them from then
+// on.
+
+use crate::days::{find_day, Day};
+use crate::generate::Rng;
+use crate::input::{data_dir, InputError};
+use crate::{ParseError, Solution};
+use std::any::Any;
+use std::fs::{create_dir_all, read_dir, read_to_string, write};
+use std::panic::{self, AssertUnwindSafe};
+use std::path::PathBuf;
+
+pub fn parses<S: Solution>(input_text: &str) -> Result<(), ParseError> {
+    S::parse(input_text).map(|_| ())
+}
+
+// Odd bits of input: edge case numbers, separators and characters no puzzle
+// uses.
+const TOKENS: [&str; 18] = [
+    "0",
+    "-1",
+    "255",
+    "4294967296",
+    "18446744073709551616",
+    "99999999999999999999999",
+    " ",
+    "\n",
+    "\n\n",
+    ",",
+    ":",
+    "-",
+    "[",
+    "]",
+    "->",
+    "old",
+    "é",
+    "\t",
+];
+
+// Mangles `text` a few times, like a bad copy and paste or a typo would.
+pub fn mutate(rng: &mut Rng, text: &str) -> String {
+    let mut chars = text.chars().collect::<Vec<char>>();
+    for _ in 0..rng.range(1..4) {
+        let at = rng.range(0..chars.len() + 1);
+        let len = rng.range(0..(chars.len() - at).min(8) + 1);
+        match rng.range(0..6) {
+            0 => {
+                chars.drain(at..at + len);
+            }
+            1 => {
+                let copy = chars[at..at + len].to_vec();
+                chars.splice(at..at, copy);
+            }
+            2 if at < chars.len() => chars[at] = *rng.pick(&chars),
+            3 => {
+                chars.splice(at..at, rng.pick(&TOKENS).chars());
+            }
+            4 => chars.truncate(at),
+            _ => {
+                // A whole line again, elsewhere.
+                let text = chars.iter().collect::<String>();
+                let lines = text.lines().collect::<Vec<&str>>();
+                if lines.is_empty() {
+                    continue;
+                }
+                let line = format!("{}\n", rng.pick(&lines));
+                let start = chars[..at]
+                    .iter()
+                    .rposition(|c| *c == '\n')
+                    .map_or(0, |i| i + 1);
+                chars.splice(start..start, line.chars());
+            }
+        }
+    }
+    chars.into_iter().collect()
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct FuzzOptions {
+    pub runs: usize,
+    pub seed: u64,
+}
+impl Default for FuzzOptions {
+    fn default() -> Self {
+        Self {
+            runs: 1000,
+            seed: 2022,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Crash {
+    pub day: u8,
+    pub input: String,
+    pub message: String,
+}
+
+// Feeds mangled generated inputs to the parser of `day` and returns the ones
+// that made it panic.
+pub fn fuzz(day: &Day, options: FuzzOptions) -> Vec<Crash> {
+    let mut rng = Rng::new(options.seed);
+    let mut crashes = vec![];
+    for _ in 0..options.runs {
+        let size = rng.range(1..12);
+        let generated = (day.generate)(&mut rng, size);
+        let input = mutate(&mut rng, &generated);
+        if let Err(message) = try_parse(day, &input) {
+            crashes.push(Crash {
+                day: day.day,
+                input,
+                message,
+            });
+        }
+    }
+    crashes
+}
+
+// Parses `input_text` as `day` does, with a panic turned into its message.
+pub fn try_parse(day: &Day, input_text: &str) -> Result<(), String> {
+    panic::catch_unwind(AssertUnwindSafe(|| (day.parse)(input_text)))
+        .map(|_| ())
+        .map_err(|payload| panic_message(payload.as_ref()))
+}
+
+pub(crate) fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "a panic without a message".to_string()
+    }
+}
+
+pub fn crashers_dir() -> PathBuf {
+    data_dir().join("fuzz")
+}
+
+// Crashing inputs are named after their content, so each is saved once.
+pub fn crasher_path(crash: &Crash) -> PathBuf {
+    // FNV-1a
+    let hash = crash
+        .input
+        .bytes()
+        .fold(0xcbf2_9ce4_8422_2325_u64, |hash, b| {
+            (hash ^ b as u64).wrapping_mul(0x0100_0000_01b3)
+        });
+    crashers_dir().join(format!("day_{:02}_{:016x}.txt", crash.day, hash))
+}
+
+pub fn save_crasher(crash: &Crash) -> Result<PathBuf, InputError> {
+    let dir = crashers_dir();
+    create_dir_all(&dir).map_err(|err| InputError::Io(dir.clone(), err))?;
+    let path = crasher_path(crash);
+    write(&path, &crash.input).map_err(|err| InputError::Io(path.clone(), err))?;
+    Ok(path)
+}
+
+// Every saved crashing input with its day. They're read as they are, without
+// the trimming of `load_input`: whitespace may be what broke the parser.
+pub fn crashers() -> Result<Vec<(&'static Day, PathBuf, String)>, InputError> {
+    let dir = crashers_dir();
+    if !dir.exists() {
+        return Ok(vec![]);
+    }
+    let mut paths = read_dir(&dir)
+        .map_err(|err| InputError::Io(dir.clone(), err))?
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter_map(|path| {
+            let name = path.file_name()?.to_str()?;
+            let day = find_day(name.strip_prefix("day_")?.get(..2)?.parse().ok()?)?;
+            Some((day, path))
+        })
+        .collect::<Vec<(&Day, PathBuf)>>();
+    paths.sort_by(|a, b| a.1.cmp(&b.1));
+    paths
+        .into_iter()
+        .map(|(day, path)| {
+            let input = read_to_string(&path).map_err(|err| InputError::Io(path.clone(), err))?;
+            Ok((day, path, input))
+        })
+        .collect()
+}
@@ -0,0 +1,133 @@
+// Generated puzzle inputs
+//
+// Every day can make up inputs of its own (`Generate`): a random value of its
+// parsed type, printed the way the puzzle prints it. Generation is seeded, so
+// a seed and a size always give the same input. `aoc gen <day>` prints one,
+// to see how solutions scale past the single real input; the fuzzer mangles
+// them, and parsing one back must give the generated value again.
+
+use crate::days::Day;
+use crate::{ParseError, Solution};
+use std::fmt;
+use std::ops::Range;
+
+// SplitMix64: small, fast and good enough to make up puzzle inputs.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+    // A number in `range`, which can't be empty.
+    pub fn range(&mut self, range: Range<usize>) -> usize {
+        assert!(!range.is_empty(), "Empty range {:?}", range);
+        range.start + (self.next_u64() % (range.end - range.start) as u64) as usize
+    }
+    pub fn signed(&mut self, range: Range<i64>) -> i64 {
+        assert!(!range.is_empty(), "Empty range {:?}", range);
+        range.start + (self.next_u64() % range.start.abs_diff(range.end)) as i64
+    }
+    pub fn chance(&mut self, percent: u64) -> bool {
+        self.next_u64() % 100 < percent
+    }
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.range(0..items.len())]
+    }
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.range(0..i + 1));
+        }
+    }
+}
+
+// Random inputs for a day's puzzle. `size` scales the input: about as many
+// lines, records or map rows, within what the puzzle allows. Generated inputs
+// are meant to be solvable, not just parseable.
+pub trait Generate: Solution<Parsed: PartialEq + fmt::Debug> {
+    fn generate(rng: &mut Rng, size: usize) -> Self::Parsed;
+    fn render(parsed: &Self::Parsed) -> String;
+}
+
+pub fn generate<S: Generate>(rng: &mut Rng, size: usize) -> String {
+    S::render(&S::generate(rng, size))
+}
+
+pub struct RoundTripError {
+    pub text: String,
+    pub generated: String,
+    pub parsed: Result<String, ParseError>,
+}
+impl fmt::Display for RoundTripError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Rendered\n{}", self.text)?;
+        match &self.parsed {
+            Ok(parsed) => writeln!(f, "which parses as\n{}", parsed)?,
+            Err(err) => writeln!(f, "which fails to parse: {}", err)?,
+        }
+        write!(f, "instead of\n{}", self.generated)
+    }
+}
+impl fmt::Debug for RoundTripError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self)
+    }
+}
+impl std::error::Error for RoundTripError {}
+
+// Renders a generated value and checks it parses back to the same value.
+pub fn round_trip<S: Generate>(rng: &mut Rng, size: usize) -> Result<(), RoundTripError> {
+    let generated = S::generate(rng, size);
+    let text = S::render(&generated);
+    match S::parse(&text) {
+        Ok(parsed) if parsed == generated => Ok(()),
+        parsed => Err(RoundTripError {
+            text,
+            generated: format!("{:#?}", generated),
+            parsed: parsed.map(|parsed| format!("{:#?}", parsed)),
+        }),
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct GenOptions {
+    pub size: usize,
+    pub seed: u64,
+}
+impl Default for GenOptions {
+    fn default() -> Self {
+        Self {
+            size: 100,
+            seed: 2022,
+        }
+    }
+}
+
+pub fn generate_input(day: &Day, options: GenOptions) -> String {
+    (day.generate)(&mut Rng::new(options.seed), options.size)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn seeded_and_in_range() {
+        let mut rng = Rng::new(7);
+        let numbers = (0..100).map(|_| rng.range(3..9)).collect::<Vec<usize>>();
+        assert!(numbers.iter().all(|n| (3..9).contains(n)));
+        assert_eq!(numbers, {
+            let mut rng = Rng::new(7);
+            (0..100).map(|_| rng.range(3..9)).collect::<Vec<usize>>()
+        });
+        assert!((0..100).all(|_| (-5..5).contains(&rng.signed(-5..5))));
+    }
+}
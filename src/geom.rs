@@ -0,0 +1,292 @@
+// Geometry
+//
+// Points and directions shared by the grid puzzles. Directions follow the
+// screen convention used by `Grid`: `y` grows downwards, so `Up` is `y - 1`.
+
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point2<T> {
+    pub x: T,
+    pub y: T,
+}
+impl<T> Point2<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+}
+impl<T: fmt::Display> fmt::Display for Point2<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+impl<T> Point3<T> {
+    pub const fn new(x: T, y: T, z: T) -> Self {
+        Self { x, y, z }
+    }
+}
+impl<T: fmt::Display> fmt::Display for Point3<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{},{}", self.x, self.y, self.z)
+    }
+}
+
+macro_rules! impl_ops {
+    ($point:ident { $($field:ident),+ }) => {
+        impl<T: Add<Output = T>> Add for $point<T> {
+            type Output = Self;
+            fn add(self, other: Self) -> Self {
+                Self { $($field: self.$field + other.$field),+ }
+            }
+        }
+        impl<T: Sub<Output = T>> Sub for $point<T> {
+            type Output = Self;
+            fn sub(self, other: Self) -> Self {
+                Self { $($field: self.$field - other.$field),+ }
+            }
+        }
+        impl<T: AddAssign> AddAssign for $point<T> {
+            fn add_assign(&mut self, other: Self) {
+                $(self.$field += other.$field;)+
+            }
+        }
+        impl<T: SubAssign> SubAssign for $point<T> {
+            fn sub_assign(&mut self, other: Self) {
+                $(self.$field -= other.$field;)+
+            }
+        }
+        impl<T: Neg<Output = T>> Neg for $point<T> {
+            type Output = Self;
+            fn neg(self) -> Self {
+                Self { $($field: -self.$field),+ }
+            }
+        }
+        impl<T: Mul<Output = T> + Copy> Mul<T> for $point<T> {
+            type Output = Self;
+            fn mul(self, scale: T) -> Self {
+                Self { $($field: self.$field * scale),+ }
+            }
+        }
+    };
+}
+impl_ops!(Point2 { x, y });
+impl_ops!(Point3 { x, y, z });
+
+macro_rules! impl_metrics {
+    ($($t:ty),+) => {$(
+        impl Point2<$t> {
+            pub fn manhattan(&self, other: &Self) -> $t {
+                (self.x.abs_diff(other.x) + self.y.abs_diff(other.y)) as $t
+            }
+            pub fn chebyshev(&self, other: &Self) -> $t {
+                self.x.abs_diff(other.x).max(self.y.abs_diff(other.y)) as $t
+            }
+        }
+        impl Point3<$t> {
+            pub fn manhattan(&self, other: &Self) -> $t {
+                (self.x.abs_diff(other.x) + self.y.abs_diff(other.y) + self.z.abs_diff(other.z))
+                    as $t
+            }
+            pub fn chebyshev(&self, other: &Self) -> $t {
+                self.x
+                    .abs_diff(other.x)
+                    .max(self.y.abs_diff(other.y))
+                    .max(self.z.abs_diff(other.z)) as $t
+            }
+        }
+    )+};
+}
+impl_metrics!(i32, i64, isize, u32, u64, usize);
+
+macro_rules! impl_signed {
+    ($($t:ty),+) => {$(
+        impl Point2<$t> {
+            pub fn signum(&self) -> Self {
+                Self::new(self.x.signum(), self.y.signum())
+            }
+        }
+        impl Point3<$t> {
+            pub fn signum(&self) -> Self {
+                Self::new(self.x.signum(), self.y.signum(), self.z.signum())
+            }
+            // The six points sharing a face with this one.
+            pub fn neighbours6(&self) -> [Self; 6] {
+                let Self { x, y, z } = *self;
+                [
+                    Self::new(x + 1, y, z),
+                    Self::new(x, y + 1, z),
+                    Self::new(x, y, z + 1),
+                    Self::new(x - 1, y, z),
+                    Self::new(x, y - 1, z),
+                    Self::new(x, y, z - 1),
+                ]
+            }
+        }
+        impl Add<Direction4> for Point2<$t> {
+            type Output = Self;
+            fn add(self, direction: Direction4) -> Self {
+                let (dx, dy) = direction.delta();
+                Self::new(self.x + dx as $t, self.y + dy as $t)
+            }
+        }
+        impl Add<Direction8> for Point2<$t> {
+            type Output = Self;
+            fn add(self, direction: Direction8) -> Self {
+                let (dx, dy) = direction.delta();
+                Self::new(self.x + dx as $t, self.y + dy as $t)
+            }
+        }
+    )+};
+}
+impl_signed!(i32, i64, isize);
+
+impl Point2<usize> {
+    // Steps in `direction` unless that crosses zero.
+    pub fn step(&self, direction: Direction4) -> Option<Self> {
+        let (dx, dy) = direction.delta();
+        Some(Self::new(
+            self.x.checked_add_signed(dx)?,
+            self.y.checked_add_signed(dy)?,
+        ))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction4 {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+impl Direction4 {
+    // Clockwise, starting up.
+    pub const ALL: [Direction4; 4] = [
+        Direction4::Up,
+        Direction4::Right,
+        Direction4::Down,
+        Direction4::Left,
+    ];
+
+    pub fn delta(self) -> (isize, isize) {
+        match self {
+            Direction4::Up => (0, -1),
+            Direction4::Right => (1, 0),
+            Direction4::Down => (0, 1),
+            Direction4::Left => (-1, 0),
+        }
+    }
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+    pub fn arrow(self) -> char {
+        match self {
+            Direction4::Up => '^',
+            Direction4::Right => '>',
+            Direction4::Down => 'v',
+            Direction4::Left => '<',
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction8 {
+    N,
+    NE,
+    E,
+    SE,
+    S,
+    SW,
+    W,
+    NW,
+}
+impl Direction8 {
+    // Clockwise, starting north.
+    pub const ALL: [Direction8; 8] = [
+        Direction8::N,
+        Direction8::NE,
+        Direction8::E,
+        Direction8::SE,
+        Direction8::S,
+        Direction8::SW,
+        Direction8::W,
+        Direction8::NW,
+    ];
+
+    pub fn delta(self) -> (isize, isize) {
+        match self {
+            Direction8::N => (0, -1),
+            Direction8::NE => (1, -1),
+            Direction8::E => (1, 0),
+            Direction8::SE => (1, 1),
+            Direction8::S => (0, 1),
+            Direction8::SW => (-1, 1),
+            Direction8::W => (-1, 0),
+            Direction8::NW => (-1, -1),
+        }
+    }
+    // Turns by 45 degrees.
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 8]
+    }
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 7) % 8]
+    }
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 4) % 8]
+    }
+}
+impl From<Direction4> for Direction8 {
+    fn from(direction: Direction4) -> Self {
+        match direction {
+            Direction4::Up => Direction8::N,
+            Direction4::Right => Direction8::E,
+            Direction4::Down => Direction8::S,
+            Direction4::Left => Direction8::W,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn distances() {
+        let (a, b) = (Point2::new(1_i32, -2), Point2::new(-3, 4));
+        assert_eq!(a + b, Point2::new(-2, 2));
+        assert_eq!(a.manhattan(&b), 10);
+        assert_eq!(a.chebyshev(&b), 6);
+        assert_eq!((b - a).signum(), Point2::new(-1, 1));
+        let c = Point3::new(1_usize, 2, 3);
+        assert_eq!(c.manhattan(&Point3::new(3, 2, 0)), 5);
+    }
+
+    #[test]
+    fn directions() {
+        assert_eq!(Direction4::Left.turn_right(), Direction4::Up);
+        assert_eq!(Direction4::Up.turn_left(), Direction4::Left);
+        assert_eq!(Direction4::Right.opposite(), Direction4::Left);
+        assert_eq!(Direction8::NW.turn_right(), Direction8::N);
+        assert_eq!(Direction8::NE.opposite(), Direction8::SW);
+        assert_eq!(Point2::new(0_i32, 0) + Direction8::SE, Point2::new(1, 1));
+        assert_eq!(Point2::new(0_usize, 0).step(Direction4::Up), None);
+        assert_eq!(
+            Point2::new(0_usize, 0).step(Direction4::Down),
+            Some(Point2::new(0, 1))
+        );
+    }
+}
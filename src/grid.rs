@@ -0,0 +1,312 @@
+// 2D grids
+//
+// A rectangular grid stored row by row. Cells are addressed as `(x, y)` with
+// `x` the column and `y` the row, both starting at the top left corner.
+// `grid[(x, y)]` (or `grid[point]`) panics out of bounds, `grid.get(x, y)`
+// doesn't.
+
+use crate::geom::Point2;
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GridError {
+    Empty,
+    Ragged {
+        line: usize,
+        expected: usize,
+        found: usize,
+    },
+    UnexpectedChar {
+        line: usize,
+        column: usize,
+        found: char,
+    },
+}
+impl fmt::Display for GridError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GridError::Empty => write!(f, "Grid has no cells"),
+            GridError::Ragged {
+                line,
+                expected,
+                found,
+            } => write!(
+                f,
+                "Line {} has {} cells, expected {}",
+                line, found, expected
+            ),
+            GridError::UnexpectedChar {
+                line,
+                column,
+                found,
+            } => write!(
+                f,
+                "Unexpected character {:?} at line {}, column {}",
+                found, line, column
+            ),
+        }
+    }
+}
+impl std::error::Error for GridError {}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self {
+        Self {
+            cells: vec![fill; width * height],
+            width,
+            height,
+        }
+    }
+    pub fn transpose(&self) -> Self {
+        let cells = (0..self.width)
+            .flat_map(|x| self.column(x).cloned())
+            .collect();
+        Self {
+            cells,
+            width: self.height,
+            height: self.width,
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    pub fn from_cells(width: usize, cells: Vec<T>) -> Self {
+        assert!(
+            width > 0 && cells.len().is_multiple_of(width),
+            "{} cells don't fill rows of {}",
+            cells.len(),
+            width
+        );
+        let height = cells.len() / width;
+        Self {
+            cells,
+            width,
+            height,
+        }
+    }
+    // Parses a character map, one row per line. Lines must all have the same
+    // length and `cell` rejects characters by returning `None`.
+    pub fn from_chars<F>(text: &str, mut cell: F) -> Result<Self, GridError>
+    where
+        F: FnMut(char) -> Option<T>,
+    {
+        let mut cells = vec![];
+        let mut width = 0;
+        let mut height = 0;
+        for (y, line) in text.lines().enumerate() {
+            let row_start = cells.len();
+            for (x, c) in line.chars().enumerate() {
+                let found = cell(c).ok_or(GridError::UnexpectedChar {
+                    line: y + 1,
+                    column: x + 1,
+                    found: c,
+                })?;
+                cells.push(found);
+            }
+            let found = cells.len() - row_start;
+            if y == 0 {
+                width = found;
+            } else if found != width {
+                return Err(GridError::Ragged {
+                    line: y + 1,
+                    expected: width,
+                    found,
+                });
+            }
+            height += 1;
+        }
+        if cells.is_empty() {
+            return Err(GridError::Empty);
+        }
+        Ok(Self {
+            cells,
+            width,
+            height,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+    pub fn height(&self) -> usize {
+        self.height
+    }
+    pub fn contains(&self, x: usize, y: usize) -> bool {
+        x < self.width && y < self.height
+    }
+    // Signed coordinates are handy when stepping off the edges.
+    pub fn checked(&self, x: isize, y: isize) -> Option<(usize, usize)> {
+        let (x, y) = (usize::try_from(x).ok()?, usize::try_from(y).ok()?);
+        self.contains(x, y).then_some((x, y))
+    }
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        self.contains(x, y).then(|| &self.cells[x + y * self.width])
+    }
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        if self.contains(x, y) {
+            Some(&mut self.cells[x + y * self.width])
+        } else {
+            None
+        }
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+    pub fn row_mut(&mut self, y: usize) -> &mut [T] {
+        &mut self.cells[y * self.width..(y + 1) * self.width]
+    }
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> + '_ {
+        assert!(x < self.width, "Column {} out of bounds", x);
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> + '_ {
+        self.cells.chunks(self.width)
+    }
+    // Appends a row at the bottom, for grids that grow as they go.
+    pub fn push_row(&mut self, row: impl IntoIterator<Item = T>) {
+        let row_start = self.cells.len();
+        self.cells.extend(row);
+        assert_eq!(
+            self.cells.len() - row_start,
+            self.width,
+            "Rows must have {} cells",
+            self.width
+        );
+        self.height += 1;
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+    pub fn position<P: FnMut(&T) -> bool>(&self, mut predicate: P) -> Option<(usize, usize)> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(p, _)| p)
+    }
+    pub fn map<U, F: FnMut(&T) -> U>(&self, f: F) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    // Up, left, right and down neighbours that are inside the grid.
+    pub fn neighbours4(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        [(0, -1), (-1, 0), (1, 0), (0, 1)]
+            .into_iter()
+            .filter_map(move |(dx, dy)| self.checked(x as isize + dx, y as isize + dy))
+    }
+    // Neighbours including diagonals, row by row.
+    pub fn neighbours8(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        (-1..=1)
+            .flat_map(|dy| (-1..=1).map(move |dx| (dx, dy)))
+            .filter(|&d| d != (0, 0))
+            .filter_map(move |(dx, dy)| self.checked(x as isize + dx, y as isize + dy))
+    }
+
+    // Draws the grid with one character per cell.
+    pub fn render<F: FnMut(&T) -> char>(&self, mut cell: F) -> String {
+        self.rows()
+            .map(|row| row.iter().map(&mut cell).collect::<String>())
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        self.get(x, y).unwrap_or_else(|| {
+            panic!(
+                "({}, {}) out of bounds for a {}x{} grid",
+                x, y, self.width, self.height
+            )
+        })
+    }
+}
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(x, y).unwrap_or_else(|| {
+            panic!(
+                "({}, {}) out of bounds for a {}x{} grid",
+                x, y, width, height
+            )
+        })
+    }
+}
+
+impl<T> Index<Point2<usize>> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point2<usize>) -> &T {
+        &self[(point.x, point.y)]
+    }
+}
+impl<T> IndexMut<Point2<usize>> for Grid<T> {
+    fn index_mut(&mut self, point: Point2<usize>) -> &mut T {
+        &mut self[(point.x, point.y)]
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_and_views() {
+        let grid = Grid::from_chars("123\n456", |c| c.to_digit(10)).unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 1)], 6);
+        assert_eq!(grid.get(3, 0), None);
+        assert_eq!(grid.row(1), &[4, 5, 6]);
+        assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), vec![2, 5]);
+        assert_eq!(grid.transpose().to_string(), "14\n25\n36");
+        assert_eq!(
+            Grid::from_chars("12\n3", |c| c.to_digit(10)),
+            Err(GridError::Ragged {
+                line: 2,
+                expected: 2,
+                found: 1
+            })
+        );
+    }
+
+    #[test]
+    fn neighbours() {
+        let grid = Grid::new(3, 3, '.');
+        assert_eq!(grid.neighbours4(0, 0).collect::<Vec<_>>(), [(1, 0), (0, 1)]);
+        assert_eq!(grid.neighbours4(1, 1).count(), 4);
+        assert_eq!(grid.neighbours8(1, 1).count(), 8);
+        assert_eq!(grid.neighbours8(2, 2).count(), 3);
+    }
+}
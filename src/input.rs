@@ -0,0 +1,153 @@
+// Puzzle input loading
+//
+// Inputs live in `data/day_NN_input.txt` next to `Cargo.toml`. Set `AOC_DATA_DIR`
+// to read them from somewhere else, or pass `-` to read from stdin.
+
+use std::env;
+use std::error::Error;
+use std::fmt;
+use std::fs::read;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+pub const DATA_DIR_ENV: &str = "AOC_DATA_DIR";
+pub const STDIN_MARKER: &str = "-";
+
+#[derive(Debug)]
+pub enum InputError {
+    NotFound(PathBuf),
+    NotUtf8(PathBuf),
+    Empty(PathBuf),
+    Malformed(PathBuf, String),
+    Io(PathBuf, io::Error),
+}
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::NotFound(path) => write!(f, "Input file {} not found", path.display()),
+            InputError::NotUtf8(path) => {
+                write!(f, "Input file {} is not valid UTF-8", path.display())
+            }
+            InputError::Empty(path) => write!(f, "Input file {} is empty", path.display()),
+            InputError::Malformed(path, reason) => {
+                write!(f, "Input file {} is malformed: {}", path.display(), reason)
+            }
+            InputError::Io(path, err) => {
+                write!(f, "Failed reading input file {}: {}", path.display(), err)
+            }
+        }
+    }
+}
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            InputError::Io(_, err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+// Where a puzzle input comes from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    Day(u8),
+    File(PathBuf),
+    Stdin,
+}
+impl InputSource {
+    pub fn from_arg(arg: &str) -> Self {
+        match arg {
+            STDIN_MARKER => InputSource::Stdin,
+            path => InputSource::File(PathBuf::from(path)),
+        }
+    }
+    pub fn load(&self) -> Result<String, InputError> {
+        match self {
+            InputSource::Day(day) => load_input(input_path(*day)),
+            InputSource::File(path) => load_input(path),
+            InputSource::Stdin => {
+                let stdin = PathBuf::from("<stdin>");
+                let mut bytes = vec![];
+                io::stdin()
+                    .read_to_end(&mut bytes)
+                    .map_err(|err| InputError::Io(stdin.clone(), err))?;
+                decode(stdin, bytes)
+            }
+        }
+    }
+}
+
+pub fn data_dir() -> PathBuf {
+    match env::var_os(DATA_DIR_ENV) {
+        Some(dir) => PathBuf::from(dir),
+        None => Path::new(env!("CARGO_MANIFEST_DIR")).join("data"),
+    }
+}
+
+pub fn input_path(day: u8) -> PathBuf {
+    data_dir().join(format!("day_{:02}_input.txt", day))
+}
+
+// Reads a whole input file. Trailing whitespace is dropped so every parser
+// sees the same text whether or not the file ends with a newline.
+pub fn load_input<P: AsRef<Path>>(path: P) -> Result<String, InputError> {
+    let path = path.as_ref().to_path_buf();
+    let bytes = read(&path).map_err(|err| match err.kind() {
+        io::ErrorKind::NotFound => InputError::NotFound(path.clone()),
+        _ => InputError::Io(path.clone(), err),
+    })?;
+    decode(path, bytes)
+}
+
+fn decode(path: PathBuf, bytes: Vec<u8>) -> Result<String, InputError> {
+    let text = String::from_utf8(bytes).map_err(|_| InputError::NotUtf8(path.clone()))?;
+    let text = text.trim_end();
+    if text.is_empty() {
+        return Err(InputError::Empty(path));
+    }
+    Ok(text.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::{create_dir_all, write};
+
+    fn scratch_file(name: &str, contents: &[u8]) -> PathBuf {
+        let dir = env::temp_dir().join("aoc2022_input_tests");
+        create_dir_all(&dir).unwrap();
+        let path = dir.join(name);
+        write(&path, contents).unwrap();
+        path
+    }
+
+    #[test]
+    fn trailing_whitespace_is_normalized() {
+        let path = scratch_file("trailing.txt", b"1\n2\n\n  \n");
+        assert_eq!(load_input(path).unwrap(), "1\n2");
+    }
+
+    #[test]
+    fn missing_file() {
+        let path = env::temp_dir().join("aoc2022_input_tests/missing.txt");
+        assert!(matches!(load_input(path), Err(InputError::NotFound(_))));
+    }
+
+    #[test]
+    fn empty_file() {
+        let path = scratch_file("empty.txt", b"\n\n");
+        assert!(matches!(load_input(path), Err(InputError::Empty(_))));
+    }
+
+    #[test]
+    fn not_utf8() {
+        let path = scratch_file("binary.txt", &[0xff, 0xfe, b'\n']);
+        assert!(matches!(load_input(path), Err(InputError::NotUtf8(_))));
+    }
+
+    #[test]
+    fn day_paths() {
+        assert!(input_path(7).ends_with("day_07_input.txt"));
+        assert_eq!(InputSource::from_arg("-"), InputSource::Stdin);
+    }
+}
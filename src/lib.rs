@@ -1,13 +1,176 @@
 // Helper library for AOC 2022
 
-use std::fs::{metadata, read_to_string};
-use std::path::Path;
-
-pub fn load_input(filename: &str) -> String {
-    match metadata(Path::new(&filename)) {
-        Ok(_) => read_to_string(filename)
-            .expect("Failed reading file {filename}")
-            .to_owned(),
-        Err(_) => panic!("Input file {filename} not found"),
+use std::fmt;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+pub mod answers;
+pub mod bench;
+pub mod days;
+pub mod examples;
+pub mod fuzz;
+pub mod generate;
+pub mod geom;
+pub mod grid;
+pub mod input;
+pub mod parse;
+pub mod pool;
+pub mod puzzles;
+pub mod reference;
+pub mod report;
+pub mod scaffold;
+pub mod search;
+pub mod trace;
+
+pub use input::{input_path, load_input, InputError, InputSource};
+pub use parse::ParseError;
+
+// Puzzle answers. `Signed` is for answers that can come out negative, at
+// least on generated inputs. `Art` is for answers drawn on a screen, like day
+// 10's CRT.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Integer(u64),
+    Signed(i64),
+    Text(String),
+    Art(String),
+}
+impl Answer {
+    pub fn is_multiline(&self) -> bool {
+        matches!(self, Answer::Art(_))
+    }
+}
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Integer(value) => write!(f, "{}", value),
+            Answer::Signed(value) => write!(f, "{}", value),
+            Answer::Text(text) => write!(f, "{}", text),
+            Answer::Art(art) => write!(f, "{}", art.trim_end()),
+        }
+    }
+}
+impl From<u64> for Answer {
+    fn from(value: u64) -> Self {
+        Answer::Integer(value)
+    }
+}
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        Answer::Integer(value as u64)
+    }
+}
+impl From<i64> for Answer {
+    fn from(value: i64) -> Self {
+        Answer::Signed(value)
+    }
+}
+impl From<isize> for Answer {
+    fn from(value: isize) -> Self {
+        Answer::Signed(value as i64)
+    }
+}
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Answer::Text(text)
+    }
+}
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Answer::Text(text.to_string())
+    }
+}
+
+// Asks a running part to stop, e.g. when it's taking too long. Parts that can
+// run for long check it now and then and stop with what they have.
+#[derive(Debug, Clone, Default)]
+pub struct Cancel(Arc<AtomicBool>);
+impl Cancel {
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+// A part stopped before it was done, with how far it got if it can tell, e.g.
+// the best answer found so far.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cancelled {
+    pub progress: Option<String>,
+}
+impl fmt::Display for Cancelled {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.progress {
+            Some(progress) => write!(f, "{}", progress),
+            None => write!(f, "no answer"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PartResult {
+    pub part: u8,
+    pub answer: Result<Answer, Cancelled>,
+    pub duration: Duration,
+}
+
+// Answers of a single run along with how long each stage took.
+#[derive(Debug, Clone)]
+pub struct Solved {
+    pub parse_duration: Duration,
+    pub parts: Vec<PartResult>,
+}
+
+// A day's puzzle. The input is parsed once and shared by both parts; a
+// malformed input is a `ParseError`, not a panic.
+pub trait Solution {
+    type Parsed;
+
+    fn parse(input_text: &str) -> Result<Self::Parsed, ParseError>;
+    fn part1(parsed: &Self::Parsed) -> Answer;
+    fn part2(parsed: &Self::Parsed) -> Answer;
+
+    // Parts that can run for long override these to stop once `cancel` is
+    // set. The others always run to the end.
+    fn part1_cancellable(parsed: &Self::Parsed, _cancel: &Cancel) -> Result<Answer, Cancelled> {
+        Ok(Self::part1(parsed))
+    }
+    fn part2_cancellable(parsed: &Self::Parsed, _cancel: &Cancel) -> Result<Answer, Cancelled> {
+        Ok(Self::part2(parsed))
+    }
+
+    fn solve(input_text: &str, parts: &[u8], cancel: &Cancel) -> Result<Solved, ParseError> {
+        let start = Instant::now();
+        let parsed = Self::parse(input_text)?;
+        let parse_duration = start.elapsed();
+        let parts = parts
+            .iter()
+            .map(|part| {
+                let start = Instant::now();
+                let answer = match part {
+                    1 => Self::part1_cancellable(&parsed, cancel),
+                    2 => Self::part2_cancellable(&parsed, cancel),
+                    p => panic!("Part {p} not found"),
+                };
+                PartResult {
+                    part: *part,
+                    answer,
+                    duration: start.elapsed(),
+                }
+            })
+            .collect();
+        Ok(Solved {
+            parse_duration,
+            parts,
+        })
+    }
+    fn solve_pt1(input_text: &str) -> Result<Answer, ParseError> {
+        Ok(Self::part1(&Self::parse(input_text)?))
+    }
+    fn solve_pt2(input_text: &str) -> Result<Answer, ParseError> {
+        Ok(Self::part2(&Self::parse(input_text)?))
     }
 }
@@ -0,0 +1,356 @@
+// Input parsing
+//
+// Helpers for the days' parsers. A `Line` is a slice of one input line that
+// remembers where it came from, so anything that fails to parse can point at
+// the line and column it choked on:
+//
+//   let line = parse::lines(input_text).next().unwrap();
+//   let id: u8 = line.after("Blueprint ")?.int()?;
+//   let [x, y] = line.ints::<isize>()[..] else { return Err(line.error("x and y")) };
+//
+// The runner tags errors with the day they came from (`with_day`), so a
+// corrupted input is reported as
+//
+//   Day 17 input, line 1, column 5: expected `<` or `>`
+//       >>><x<
+//           ^
+
+use crate::grid::{Grid, GridError};
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: Option<u8>,
+    pub line: usize,
+    pub column: usize,
+    pub snippet: String,
+    pub expected: String,
+}
+impl ParseError {
+    pub fn with_day(self, day: u8) -> Self {
+        Self {
+            day: Some(day),
+            ..self
+        }
+    }
+}
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(day) = self.day {
+            write!(f, "Day {:02} input, ", day)?;
+        }
+        writeln!(
+            f,
+            "line {}, column {}: expected {}",
+            self.line, self.column, self.expected
+        )?;
+        writeln!(f, "    {}", self.snippet)?;
+        write!(f, "    {}^", " ".repeat(self.column.saturating_sub(1)))
+    }
+}
+// `main` reports errors with `Debug`, which should read like `Display`.
+impl fmt::Debug for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self)
+    }
+}
+impl std::error::Error for ParseError {}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Line<'a> {
+    number: usize,
+    full: &'a str,
+    text: &'a str,
+}
+impl<'a> Line<'a> {
+    // `number` starts at 1, like editors do.
+    pub fn new(number: usize, text: &'a str) -> Self {
+        Self {
+            number,
+            full: text,
+            text,
+        }
+    }
+    pub fn number(&self) -> usize {
+        self.number
+    }
+    pub fn text(&self) -> &'a str {
+        self.text
+    }
+    pub fn column(&self) -> usize {
+        self.text.as_ptr() as usize - self.full.as_ptr() as usize + 1
+    }
+    fn slice(&self, text: &'a str) -> Self {
+        Self { text, ..*self }
+    }
+    // An error pointing at the start of this slice.
+    pub fn error(&self, expected: impl Into<String>) -> ParseError {
+        ParseError {
+            day: None,
+            line: self.number,
+            column: self.column(),
+            snippet: self.full.to_string(),
+            expected: expected.into(),
+        }
+    }
+
+    // The rest of the slice from byte `index` on.
+    pub fn skip(&self, index: usize) -> Self {
+        self.slice(&self.text[index..])
+    }
+    pub fn trim(&self) -> Self {
+        self.slice(self.text.trim())
+    }
+    pub fn is_empty(&self) -> bool {
+        self.text.is_empty()
+    }
+    pub fn strip_prefix(&self, prefix: &str) -> Result<Self, ParseError> {
+        self.text
+            .strip_prefix(prefix)
+            .map(|rest| self.slice(rest))
+            .ok_or_else(|| self.error(format!("`{}`", prefix)))
+    }
+    pub fn strip_suffix(&self, suffix: &str) -> Result<Self, ParseError> {
+        self.text
+            .strip_suffix(suffix)
+            .map(|rest| self.slice(rest))
+            .ok_or_else(|| {
+                self.slice(&self.text[self.text.len()..])
+                    .error(format!("`{}`", suffix))
+            })
+    }
+    // The rest of the line after the first `label`.
+    pub fn after(&self, label: &str) -> Result<Self, ParseError> {
+        self.text
+            .find(label)
+            .map(|start| self.slice(&self.text[start + label.len()..]))
+            .ok_or_else(|| self.error(format!("`{}`", label)))
+    }
+    pub fn split_once(&self, delimiter: &str) -> Result<(Self, Self), ParseError> {
+        self.text
+            .split_once(delimiter)
+            .map(|(left, right)| (self.slice(left), self.slice(right)))
+            .ok_or_else(|| self.error(format!("`{}`", delimiter)))
+    }
+    // Delimited records, e.g. the `1,2,3` of a CSV-like line.
+    pub fn split(&self, delimiter: &'a str) -> impl Iterator<Item = Line<'a>> + 'a {
+        let line = *self;
+        self.text.split(delimiter).map(move |part| line.slice(part))
+    }
+    pub fn words(&self) -> impl Iterator<Item = Line<'a>> + 'a {
+        let line = *self;
+        self.text
+            .split_whitespace()
+            .map(move |word| line.slice(word))
+    }
+
+    // The whole slice, surrounding whitespace aside, as a `T`.
+    pub fn parse<T: FromStr>(&self) -> Result<T, ParseError> {
+        let trimmed = self.trim();
+        trimmed
+            .text
+            .parse::<T>()
+            .map_err(|_| trimmed.error(type_name::<T>()))
+    }
+    // The integer at the start of the slice and whatever follows it.
+    pub fn take_int<T: FromStr>(&self) -> Result<(T, Self), ParseError> {
+        let end = int_end(self.text, 0).ok_or_else(|| self.error("an integer"))?;
+        let value = self.slice(&self.text[..end]).parse()?;
+        Ok((value, self.slice(&self.text[end..])))
+    }
+    // The first integer anywhere in the slice.
+    pub fn int<T: FromStr>(&self) -> Result<T, ParseError> {
+        self.ints_at()
+            .next()
+            .ok_or_else(|| self.error("an integer"))
+            .and_then(|int| int.parse())
+    }
+    // Every integer in the slice, ignoring whatever is around them.
+    pub fn ints<T: FromStr>(&self) -> Vec<T> {
+        self.ints_at().filter_map(|int| int.parse().ok()).collect()
+    }
+    fn ints_at(&self) -> impl Iterator<Item = Line<'a>> + 'a {
+        let line = *self;
+        let mut start = 0;
+        std::iter::from_fn(move || {
+            while start < line.text.len() {
+                // In `1-2` the dash is a separator, not a sign.
+                let bytes = line.text.as_bytes();
+                let separator = start > 0 && bytes[start - 1].is_ascii_digit();
+                if separator && matches!(bytes[start], b'-' | b'+') {
+                    start += 1;
+                    continue;
+                }
+                if let Some(end) = int_end(line.text, start) {
+                    let int = line.slice(&line.text[start..end]);
+                    start = end;
+                    return Some(int);
+                }
+                start += line.text[start..].chars().next().map_or(1, char::len_utf8);
+            }
+            None
+        })
+    }
+    // The value right after `label`, up to the next space or punctuation.
+    pub fn field<T: FromStr>(&self, label: &str) -> Result<T, ParseError> {
+        let rest = self.after(label)?.trim();
+        let end = rest
+            .text
+            .find(|c: char| c.is_whitespace() || ",;:".contains(c))
+            .unwrap_or(rest.text.len());
+        rest.slice(&rest.text[..end]).parse()
+    }
+}
+
+// End of the integer starting at `start`, if there is one. A sign only counts
+// right before a digit.
+fn int_end(text: &str, start: usize) -> Option<usize> {
+    let bytes = text.as_bytes();
+    let digits = match bytes.get(start) {
+        Some(b'-') | Some(b'+') => start + 1,
+        _ => start,
+    };
+    let end = digits
+        + bytes[digits.min(bytes.len())..]
+            .iter()
+            .take_while(|b| b.is_ascii_digit())
+            .count();
+    (end > digits).then_some(end)
+}
+
+fn type_name<T>() -> String {
+    let name = std::any::type_name::<T>();
+    let name = name.rsplit("::").next().unwrap_or(name);
+    match name {
+        "u8" | "u16" | "u32" | "u64" | "u128" | "usize" => {
+            format!("a non-negative integer ({})", name)
+        }
+        "i8" | "i16" | "i32" | "i64" | "i128" | "isize" => format!("an integer ({})", name),
+        name => format!("a valid {}", name),
+    }
+}
+
+pub fn lines(text: &str) -> impl Iterator<Item = Line<'_>> {
+    text.lines()
+        .enumerate()
+        .map(|(i, line)| Line::new(i + 1, line))
+}
+
+// Groups of lines separated by blank lines.
+pub fn blocks(text: &str) -> Vec<Vec<Line<'_>>> {
+    let mut blocks = vec![];
+    let mut block = vec![];
+    for line in lines(text) {
+        if line.text.trim().is_empty() {
+            if !block.is_empty() {
+                blocks.push(std::mem::take(&mut block));
+            }
+        } else {
+            block.push(line);
+        }
+    }
+    if !block.is_empty() {
+        blocks.push(block);
+    }
+    blocks
+}
+
+// The first line of `text`, for inputs that are a single line. Blank input
+// still gives a line to point errors at.
+pub fn first_line(text: &str) -> Line<'_> {
+    lines(text).next().unwrap_or(Line::new(1, ""))
+}
+
+// A character map as a `Grid`, with errors pointing at the offending cell.
+pub fn grid<T, F>(text: &str, expected: &str, cell: F) -> Result<Grid<T>, ParseError>
+where
+    F: FnMut(char) -> Option<T>,
+{
+    Grid::from_chars(text, cell).map_err(|err| {
+        let line = |number: usize| Line::new(number, text.lines().nth(number - 1).unwrap_or(""));
+        match err {
+            GridError::Empty => first_line(text).error(expected),
+            GridError::UnexpectedChar {
+                line: number,
+                column,
+                ..
+            } => {
+                let line = line(number);
+                let start = line
+                    .text
+                    .char_indices()
+                    .nth(column - 1)
+                    .map_or(0, |(i, _)| i);
+                line.skip(start).error(expected)
+            }
+            GridError::Ragged {
+                line: number,
+                expected: width,
+                ..
+            } => {
+                let line = line(number);
+                let end = line
+                    .text
+                    .char_indices()
+                    .nth(width)
+                    .map_or(line.text.len(), |(i, _)| i);
+                line.skip(end).error(format!("rows of {} cells", width))
+            }
+        }
+    })
+}
+
+// Every integer in `text`, for when only the numbers matter.
+pub fn ints<T: FromStr>(text: &str) -> Vec<T> {
+    Line::new(1, text).ints()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn integers() {
+        let line = Line::new(3, "Sensor at x=2, y=-18: beacon at x=-2, y=15");
+        assert_eq!(line.ints::<i32>(), vec![2, -18, -2, 15]);
+        assert_eq!(line.field::<i32>("y=").unwrap(), -18);
+        assert_eq!(ints::<u8>("a-b 1-2"), vec![1, 2]);
+        let (value, rest) = Line::new(1, "-12,3").take_int::<i8>().unwrap();
+        assert_eq!((value, rest.text()), (-12, ",3"));
+    }
+
+    #[test]
+    fn errors_point_at_the_problem() {
+        let line = Line::new(2, "Monkey x:");
+        let err = line.after("Monkey ").unwrap().take_int::<u8>().unwrap_err();
+        assert_eq!((err.line, err.column), (2, 8));
+        assert_eq!(err.expected, "an integer");
+        let err = Line::new(1, "300").parse::<u8>().unwrap_err();
+        assert_eq!(err.expected, "a non-negative integer (u8)");
+        assert!(err.to_string().ends_with("    300\n    ^"));
+        let err = err.with_day(3);
+        assert!(err
+            .to_string()
+            .starts_with("Day 03 input, line 1, column 1:"));
+    }
+
+    #[test]
+    fn grid_errors() {
+        let err = grid("ab\ncx", "a, b or c", |c| "abc".contains(c).then_some(c)).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 2));
+        let err = grid("ab\nabc", "a letter", Some).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 3));
+        assert_eq!(err.expected, "rows of 2 cells");
+    }
+
+    #[test]
+    fn blocks_keep_line_numbers() {
+        let blocks = blocks("a\nb\n\n\nc\n");
+        let numbers = blocks
+            .iter()
+            .map(|b| b.iter().map(|l| l.number()).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        assert_eq!(numbers, vec![vec![1, 2], vec![5]]);
+    }
+}
@@ -0,0 +1,215 @@
+// Parallel runs
+//
+// Every day and part is a job of its own, taken by the first free worker of a
+// small pool of threads. A job that panics, can't parse its input or outlives
+// its timeout fails on its own without stopping the others. A job past its
+// timeout is cancelled: parts that check for it stop with their progress so
+// far, the others are left to finish in the background.
+
+use crate::bench::format_duration;
+use crate::days::Day;
+use crate::fuzz::panic_message;
+use crate::{Cancel, ParseError, PartResult};
+use std::collections::{BTreeMap, VecDeque};
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+// Like the main thread's, some solutions recurse deeply.
+const STACK_SIZE: usize = 8 << 20;
+// How long a cancelled job has to wrap up.
+const GRACE: Duration = Duration::from_secs(1);
+
+#[derive(Debug, Clone, Copy)]
+pub struct PoolOptions {
+    pub jobs: usize,
+    pub timeout: Option<Duration>,
+}
+impl Default for PoolOptions {
+    fn default() -> Self {
+        Self {
+            jobs: thread::available_parallelism().map_or(1, |n| n.get()),
+            timeout: None,
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct Job {
+    pub day: &'static Day,
+    pub part: u8,
+    pub input: Arc<str>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum JobError {
+    Parse(ParseError),
+    Panic(String),
+    Timeout(Duration),
+}
+impl fmt::Display for JobError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            JobError::Parse(err) => write!(f, "{}", err),
+            JobError::Panic(message) => write!(f, "panicked: {}", message),
+            JobError::Timeout(timeout) => {
+                write!(f, "timed out after {}", format_duration(*timeout))
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct JobResult {
+    pub day: u8,
+    pub part: u8,
+    // Wall time, parsing included.
+    pub duration: Duration,
+    pub outcome: Result<PartResult, JobError>,
+}
+
+// Runs `jobs` on `options.jobs` threads and hands each result to `report` in
+// the order of `jobs`, as soon as it and the ones before it are done.
+pub fn run_jobs(jobs: Vec<Job>, options: PoolOptions, mut report: impl FnMut(JobResult)) {
+    let count = jobs.len();
+    let queue = Arc::new(Mutex::new(
+        jobs.into_iter().enumerate().collect::<VecDeque<_>>(),
+    ));
+    let (sender, receiver) = mpsc::channel();
+    for _ in 0..options.jobs.clamp(1, count.max(1)) {
+        let (queue, sender) = (Arc::clone(&queue), sender.clone());
+        thread::spawn(move || loop {
+            let next = queue.lock().unwrap().pop_front();
+            let Some((i, job)) = next else {
+                break;
+            };
+            if sender.send((i, run_job(job, options.timeout))).is_err() {
+                break;
+            }
+        });
+    }
+    drop(sender);
+    let mut done = BTreeMap::new();
+    let mut next = 0;
+    for (i, result) in receiver {
+        done.insert(i, result);
+        while let Some(result) = done.remove(&next) {
+            report(result);
+            next += 1;
+        }
+    }
+}
+
+// Parses the input and solves one part on the current thread, catching a
+// panic in either.
+pub fn run_part(
+    day: &Day,
+    input_text: &str,
+    part: u8,
+    cancel: &Cancel,
+) -> Result<PartResult, JobError> {
+    match panic::catch_unwind(AssertUnwindSafe(|| {
+        day.run_cancellable(input_text, &[part], cancel)
+    })) {
+        Ok(Ok(mut solved)) => Ok(solved.parts.remove(0)),
+        Ok(Err(err)) => Err(JobError::Parse(err)),
+        Err(payload) => Err(JobError::Panic(panic_message(payload.as_ref()))),
+    }
+}
+
+fn run_job(job: Job, timeout: Option<Duration>) -> JobResult {
+    let (day, part) = (job.day.day, job.part);
+    let start = Instant::now();
+    let (sender, receiver) = mpsc::channel();
+    let cancel = Cancel::default();
+    let job_cancel = cancel.clone();
+    thread::Builder::new()
+        .name(format!("day {:02} part {}", day, part))
+        .stack_size(STACK_SIZE)
+        .spawn(move || {
+            let outcome = run_part(job.day, &job.input, job.part, &job_cancel);
+            // Nobody is listening any more after a timeout.
+            let _ = sender.send(outcome);
+        })
+        .expect("Spawning a job thread");
+    let solved = match timeout {
+        Some(timeout) => receiver.recv_timeout(timeout).or_else(|err| match err {
+            RecvTimeoutError::Timeout => {
+                cancel.cancel();
+                receiver.recv_timeout(GRACE)
+            }
+            err => Err(err),
+        }),
+        None => receiver.recv().map_err(|_| RecvTimeoutError::Disconnected),
+    };
+    let outcome = match solved {
+        Ok(outcome) => outcome,
+        Err(RecvTimeoutError::Timeout) => Err(JobError::Timeout(start.elapsed())),
+        Err(RecvTimeoutError::Disconnected) => Err(JobError::Panic(
+            "the job died without an answer".to_string(),
+        )),
+    };
+    JobResult {
+        day,
+        part,
+        duration: start.elapsed(),
+        outcome,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::find_day;
+    use crate::generate::Rng;
+
+    #[test]
+    fn results_in_job_order() {
+        let jobs = [(1, 2), (1, 1), (6, 1)]
+            .map(|(day, part)| Job {
+                day: find_day(day).unwrap(),
+                part,
+                input: Arc::from(match day {
+                    1 => "1000\n2000\n\n3000",
+                    _ => "not a number",
+                }),
+            })
+            .to_vec();
+        let mut results = vec![];
+        run_jobs(jobs, PoolOptions::default(), |result| results.push(result));
+        let order = results
+            .iter()
+            .map(|result| (result.day, result.part))
+            .collect::<Vec<(u8, u8)>>();
+        assert_eq!(order, [(1, 2), (1, 1), (6, 1)]);
+        assert_eq!(
+            results[1].outcome.as_ref().map(|part| part.answer.clone()),
+            Ok(Ok(3000_u64.into()))
+        );
+    }
+
+    #[test]
+    fn slow_parts_are_cancelled_with_their_progress() {
+        // Elves spreading out for hundreds of rounds.
+        let day = find_day(23).unwrap();
+        let job = Job {
+            day,
+            part: 2,
+            input: Arc::from((day.generate)(&mut Rng::new(2022), 80)),
+        };
+        let options = PoolOptions {
+            jobs: 1,
+            timeout: Some(Duration::from_millis(1)),
+        };
+        let mut results = vec![];
+        run_jobs(vec![job], options, |result| results.push(result));
+        let answer = results[0].outcome.as_ref().map(|part| part.answer.clone());
+        let Ok(Err(cancelled)) = answer else {
+            panic!("Not cancelled: {:?}", answer);
+        };
+        assert!(cancelled.to_string().starts_with("still moving after "));
+    }
+}
@@ -1,48 +1,50 @@
 // Advent Of Code
-// https://adventofcode.com/2022/day/4
+// https://adventofcode.com/2022/day/{day}
 //
-//  --- PROBLEM DESCRIPTION ---
-
-use aoc2022::load_input;
-use std::error::Error;
+// --- Day {day}: {title} ---
+// Puzzle description: puzzles/day_{NN}.md
+//
+// Input: data/day_{NN}_input.txt
 
-fn solve_pt1(input_text: &str) -> u64 {
-    0
-}
+use crate::days::Day;
+use crate::generate::{Generate, Rng};
+use crate::parse::{self, ParseError};
+use crate::{Answer, Solution};
 
-fn solve_pt2(input_text: &str) -> u64 {
-    1
+fn parse_input(input_text: &str) -> Result<Vec<String>, ParseError> {
+    parse::lines(input_text)
+        .map(|line| Ok(line.text().to_string()))
+        .collect()
 }
 
-fn main() -> Result<(), Box<dyn Error>> {
-    const FILENAME: &str = "data/day_X_input.txt";
-    let input_text = load_input(FILENAME);
-
-    print!("Part one: {:#?}\n", solve_pt1(&input_text));
-    // Correct: AAA
+struct {Name};
+impl Solution for {Name} {
+    type Parsed = Vec<String>;
 
-    print!("Part two: {:#?}\n", solve_pt2(&input_text));
-    // Correct: BBB
-
-    Ok(())
+    fn parse(input_text: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input_text)
+    }
+    fn part1(_parsed: &Self::Parsed) -> Answer {
+        0_u64.into()
+    }
+    fn part2(_parsed: &Self::Parsed) -> Answer {
+        1_u64.into()
+    }
 }
 
-// Example tests
-#[cfg(test)]
-mod example {
-    use super::*;
-
-    const TEST_DATA: &str = "Your data here";
-    const ANS_PT1: u64 = 0;
-    const ANS_PT2: u64 = 1;
-
-    #[test]
-    fn test_pt1() {
-        assert_eq!(solve_pt1(TEST_DATA), ANS_PT1);
+impl Generate for {Name} {
+    // Replace with inputs shaped like the puzzle's, for `aoc fuzz`.
+    fn generate(rng: &mut Rng, size: usize) -> Self::Parsed {
+        (0..size.max(1))
+            .map(|_| rng.range(0..1000).to_string())
+            .collect()
     }
-
-    #[test]
-    fn test_pt2() {
-        assert_eq!(solve_pt2(TEST_DATA), ANS_PT2);
+    fn render(parsed: &Self::Parsed) -> String {
+        parsed.join("\n")
     }
 }
+
+pub const DAY: Day = Day::new::<{Name}>({day}, "{title}");
+
+// Examples go in data/day_{NN}_example_K.txt with their answers in
+// data/day_{NN}_example_K.answers and run with `cargo test day_{NN}`.
@@ -0,0 +1,187 @@
+// Puzzle descriptions
+//
+// The text of each puzzle lives in `puzzles/day_NN.md`, away from the
+// solution, and `aoc describe <day>` prints it.
+//
+// The descriptions used to be pasted as `//` comments at the top of every
+// day. `extract` turns such a header into markdown and a short header that
+// points at it; `aoc extract-descriptions` ran it over `src/days/` once.
+// Multi-line paragraphs of the pasted text are examples (maps, listings,
+// program traces) and become code blocks, except for whole sentences.
+
+use crate::input::{load_input, InputError};
+use std::fs::{read_dir, read_to_string, write};
+use std::path::{Path, PathBuf};
+
+pub fn puzzles_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("puzzles")
+}
+
+pub fn description_path(day: u8) -> PathBuf {
+    puzzles_dir().join(description_name(day))
+}
+
+pub fn description_name(day: u8) -> String {
+    format!("day_{:02}.md", day)
+}
+
+pub fn load_description(day: u8) -> Result<String, InputError> {
+    load_input(description_path(day))
+}
+
+// The source header pointing at the description of `day`.
+pub fn header(day: u8, title: &str) -> String {
+    format!(
+        "// Advent Of Code\n// https://adventofcode.com/2022/day/{day}\n//\n// --- Day {day}: {title} ---\n// Puzzle description: puzzles/day_{day:02}.md\n"
+    )
+}
+
+// The start of a description, before any puzzle text.
+pub fn title_markdown(day: u8, title: &str) -> String {
+    format!("# Day {day}: {title}\n\nhttps://adventofcode.com/2022/day/{day}\n")
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Extracted {
+    pub markdown: String,
+    pub source: String,
+}
+
+// Splits the pasted puzzle text off the top of a day's source. `None` when
+// there is none left to move.
+pub fn extract(day: u8, source: &str) -> Option<Extracted> {
+    let lines = source.lines().collect::<Vec<&str>>();
+    let header_end = lines
+        .iter()
+        .position(|line| !line.starts_with("//"))
+        .unwrap_or(lines.len());
+    let text = lines[..header_end]
+        .iter()
+        .map(|line| {
+            let line = &line[2..];
+            line.strip_prefix(' ').unwrap_or(line)
+        })
+        .collect::<Vec<&str>>();
+    let title_at = text.iter().position(|line| {
+        let line = line.trim();
+        line.starts_with("--- Day ") && line.ends_with("---")
+    })?;
+    let title = text[title_at]
+        .trim()
+        .trim_matches('-')
+        .trim()
+        .split_once(": ")?
+        .1;
+    let prose = &text[title_at + 1..];
+    let moved = |line: &&str| line.trim().is_empty() || line.starts_with("Puzzle description:");
+    if prose.iter().all(moved) {
+        return None;
+    }
+
+    // "--- Part Two ---" is usually glued to the paragraph after it.
+    let prose = prose
+        .iter()
+        .flat_map(|line| match line.trim() {
+            "--- Part Two ---" => vec!["", line.trim(), ""],
+            _ => vec![*line],
+        })
+        .collect::<Vec<&str>>();
+
+    let mut markdown = title_markdown(day, title);
+    for paragraph in prose.split(|line| line.trim().is_empty()) {
+        match paragraph {
+            [] => continue,
+            [line] if line.trim() == "--- Part Two ---" => markdown.push_str("\n## Part Two\n"),
+            [line] => markdown.push_str(&format!("\n{}\n", line.trim_end())),
+            lines => {
+                // Sentences glued to an example still read as text.
+                for run in lines.chunk_by(|a, b| is_sentence(a) == is_sentence(b)) {
+                    if is_sentence(run[0]) {
+                        for line in run {
+                            markdown.push_str(&format!("\n{}\n", line.trim_end()));
+                        }
+                        continue;
+                    }
+                    markdown.push_str("\n```\n");
+                    for line in run {
+                        markdown.push_str(line.trim_end());
+                        markdown.push('\n');
+                    }
+                    markdown.push_str("```\n");
+                }
+            }
+        }
+    }
+    let mut code = header(day, title);
+    for line in &lines[header_end..] {
+        code.push_str(line);
+        code.push('\n');
+    }
+    Some(Extracted {
+        markdown,
+        source: code,
+    })
+}
+
+fn is_sentence(line: &str) -> bool {
+    let line = line.trim_end();
+    !line.starts_with(char::is_whitespace)
+        && line.split_whitespace().count() >= 5
+        && line.ends_with(['.', '?', '!', ':'])
+}
+
+// Moves the puzzle text of every `src/days/day_NN_*.rs` into
+// `puzzles/day_NN.md`. Descriptions that already exist are kept.
+pub fn extract_all(src_dir: &Path) -> Result<Vec<PathBuf>, InputError> {
+    let days_dir = src_dir.join("days");
+    let mut sources = read_dir(&days_dir)
+        .map_err(|err| InputError::Io(days_dir.clone(), err))?
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter_map(|path| {
+            let name = path.file_name()?.to_str()?;
+            let day = name.strip_prefix("day_")?.get(..2)?.parse::<u8>().ok()?;
+            Some((day, path))
+        })
+        .collect::<Vec<(u8, PathBuf)>>();
+    sources.sort();
+    std::fs::create_dir_all(puzzles_dir()).map_err(|err| InputError::Io(puzzles_dir(), err))?;
+
+    let mut written = vec![];
+    for (day, path) in sources {
+        let description = description_path(day);
+        if description.exists() {
+            continue;
+        }
+        let source = read_to_string(&path).map_err(|err| InputError::Io(path.clone(), err))?;
+        let Some(extracted) = extract(day, &source) else {
+            continue;
+        };
+        write(&description, extracted.markdown)
+            .map_err(|err| InputError::Io(description.clone(), err))?;
+        write(&path, extracted.source).map_err(|err| InputError::Io(path.clone(), err))?;
+        written.push(description);
+        written.push(path);
+    }
+    Ok(written)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn moves_the_header_to_markdown() {
+        let source = "// Advent Of Code\n// https://adventofcode.com/2022/day/4\n//\n//  --- Day 5: Supply Stacks ---\n// Some prose.\n//\n//     [D]\n// [N] [C]\n//\n// --- Part Two ---\n// More prose.\n\nuse crate::days::Day;\n";
+        let extracted = extract(5, source).unwrap();
+        assert_eq!(
+            extracted.markdown,
+            "# Day 5: Supply Stacks\n\nhttps://adventofcode.com/2022/day/5\n\nSome prose.\n\n```\n    [D]\n[N] [C]\n```\n\n## Part Two\n\nMore prose.\n"
+        );
+        assert_eq!(
+            extracted.source,
+            "// Advent Of Code\n// https://adventofcode.com/2022/day/5\n//\n// --- Day 5: Supply Stacks ---\n// Puzzle description: puzzles/day_05.md\n\nuse crate::days::Day;\n"
+        );
+        assert_eq!(extract(5, &extracted.source), None);
+    }
+}
@@ -0,0 +1,120 @@
+// Reference solutions and differential testing
+//
+// Some days rely on shortcuts that are only checked against the example.
+// Those days also implement `Reference`: slow but plainly correct versions of
+// both parts, usually simulating the puzzle as it's told. `aoc diff <days>`
+// solves generated inputs both ways and reports the first one they disagree
+// on, smallest inputs first.
+
+use crate::days::Day;
+use crate::fuzz::panic_message;
+use crate::generate::{Generate, Rng};
+use crate::{Answer, ParseError};
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+
+pub trait Reference: Generate {
+    fn reference_part1(parsed: &Self::Parsed) -> Answer;
+    fn reference_part2(parsed: &Self::Parsed) -> Answer;
+}
+
+// A part solved differently by each solution. A solution that panicked has
+// its message instead of an answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mismatch {
+    pub part: u8,
+    pub fast: Result<String, String>,
+    pub reference: Result<String, String>,
+}
+impl fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let show = |answer: &Result<String, String>| match answer {
+            Ok(answer) => answer.clone(),
+            Err(message) => format!("panicked: {}", message),
+        };
+        write!(
+            f,
+            "part {}: {} but the reference says {}",
+            self.part,
+            show(&self.fast),
+            show(&self.reference)
+        )
+    }
+}
+
+fn answer(solve: impl FnOnce() -> Answer) -> Result<String, String> {
+    panic::catch_unwind(AssertUnwindSafe(solve))
+        .map(|answer| answer.to_string())
+        .map_err(|payload| panic_message(payload.as_ref()))
+}
+
+pub type Compare = fn(&str) -> Result<Vec<Mismatch>, ParseError>;
+
+// Solves `input_text` both ways and returns the parts they disagree on.
+pub fn compare<S: Reference>(input_text: &str) -> Result<Vec<Mismatch>, ParseError> {
+    let parsed = S::parse(input_text)?;
+    let mismatch =
+        |part, fast: fn(&S::Parsed) -> Answer, reference: fn(&S::Parsed) -> Answer| Mismatch {
+            part,
+            fast: answer(|| fast(&parsed)),
+            reference: answer(|| reference(&parsed)),
+        };
+    Ok([
+        mismatch(1, S::part1, S::reference_part1),
+        mismatch(2, S::part2, S::reference_part2),
+    ]
+    .into_iter()
+    .filter(|mismatch| mismatch.fast != mismatch.reference)
+    .collect())
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct DiffOptions {
+    pub runs: usize,
+    pub size: usize,
+    pub seed: u64,
+}
+impl Default for DiffOptions {
+    fn default() -> Self {
+        Self {
+            runs: 20,
+            size: 10,
+            seed: 2022,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Disagreement {
+    pub day: u8,
+    pub input: String,
+    pub mismatches: Vec<Mismatch>,
+}
+impl fmt::Display for Disagreement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for mismatch in &self.mismatches {
+            writeln!(f, "Day {} {}", self.day, mismatch)?;
+        }
+        write!(f, "with the input\n{}", self.input)
+    }
+}
+
+// Solves generated inputs for `day` both ways, sizes going up from 1 to
+// `options.size`, until the solutions disagree. `None` if they never do, or
+// if the day has no reference solution.
+pub fn differential(day: &Day, options: DiffOptions) -> Option<Disagreement> {
+    let compare = day.compare?;
+    let mut rng = Rng::new(options.seed);
+    (0..options.runs).find_map(|run| {
+        let input = (day.generate)(&mut rng, 1 + run % options.size.max(1));
+        let mismatches = compare(&input).expect("Generated inputs parse");
+        if mismatches.is_empty() {
+            return None;
+        }
+        Some(Disagreement {
+            day: day.day,
+            input,
+            mismatches,
+        })
+    })
+}
@@ -0,0 +1,198 @@
+// Run reports
+//
+// One record per solved part, printed as the runner's usual text or as JSON/CSV
+// for tools that want to ingest runs.
+
+use crate::answers::Verdict;
+use crate::bench::format_duration;
+use crate::Answer;
+use std::fmt;
+use std::str::FromStr;
+use std::time::Duration;
+
+#[derive(Debug, Clone)]
+pub struct Record {
+    pub day: u8,
+    pub part: u8,
+    pub answer: Answer,
+    pub duration: Duration,
+    pub verdict: Verdict,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    Json,
+    Csv,
+}
+
+#[derive(Debug)]
+pub struct ParseFormatError(String);
+impl fmt::Display for ParseFormatError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Format {} not found, use text, json or csv", self.0)
+    }
+}
+impl std::error::Error for ParseFormatError {}
+
+impl FromStr for Format {
+    type Err = ParseFormatError;
+
+    fn from_str(format: &str) -> Result<Self, Self::Err> {
+        match format {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            other => Err(ParseFormatError(other.to_string())),
+        }
+    }
+}
+
+// Streams records in the chosen format. `begin` and `end` wrap the records,
+// e.g. with the CSV header or the JSON array brackets.
+pub struct Reporter {
+    format: Format,
+    written: usize,
+}
+impl Reporter {
+    pub fn new(format: Format) -> Self {
+        Self { format, written: 0 }
+    }
+    pub fn begin(&self) -> Option<String> {
+        match self.format {
+            Format::Text => None,
+            Format::Json => Some("[".to_string()),
+            Format::Csv => Some("day,part,answer,duration_ns,status,expected".to_string()),
+        }
+    }
+    pub fn record(&mut self, record: &Record) -> String {
+        self.written += 1;
+        match self.format {
+            Format::Text => text_record(record),
+            Format::Json => {
+                let separator = if self.written > 1 { "," } else { "" };
+                format!("{}{}", separator, json_record(record))
+            }
+            Format::Csv => csv_record(record),
+        }
+    }
+    pub fn end(&self) -> Option<String> {
+        match self.format {
+            Format::Json => Some("]".to_string()),
+            _ => None,
+        }
+    }
+}
+
+fn expected(verdict: &Verdict) -> Option<&str> {
+    match verdict {
+        Verdict::Fail(expected) => Some(expected),
+        _ => None,
+    }
+}
+
+fn text_record(record: &Record) -> String {
+    let Record {
+        day,
+        part,
+        answer,
+        duration,
+        verdict,
+    } = record;
+    let duration = format_duration(*duration);
+    let mut text = if answer.is_multiline() {
+        format!("Day {day:02} part {part}: [{verdict}] ({duration})\n{answer}")
+    } else {
+        format!("Day {day:02} part {part}: {answer} [{verdict}] ({duration})")
+    };
+    if let Some(expected) = expected(verdict) {
+        text += &format!("\n    expected: {expected}");
+    }
+    text
+}
+
+fn json_record(record: &Record) -> String {
+    let expected = expected(&record.verdict)
+        .map(json_string)
+        .unwrap_or_else(|| "null".to_string());
+    format!(
+        "{{\"day\":{},\"part\":{},\"answer\":{},\"duration_ns\":{},\"status\":\"{}\",\"expected\":{}}}",
+        record.day,
+        record.part,
+        json_string(&record.answer.to_string()),
+        record.duration.as_nanos(),
+        record.verdict,
+        expected
+    )
+}
+
+fn json_string(text: &str) -> String {
+    let mut json = String::from("\"");
+    for c in text.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if (c as u32) < 0x20 => json.push_str(&format!("\\u{:04x}", c as u32)),
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+    json
+}
+
+fn csv_record(record: &Record) -> String {
+    format!(
+        "{},{},{},{},{},{}",
+        record.day,
+        record.part,
+        csv_field(&record.answer.to_string()),
+        record.duration.as_nanos(),
+        record.verdict,
+        csv_field(expected(&record.verdict).unwrap_or(""))
+    )
+}
+
+fn csv_field(text: &str) -> String {
+    if text.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        text.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(answer: Answer, verdict: Verdict) -> Record {
+        Record {
+            day: 10,
+            part: 2,
+            answer,
+            duration: Duration::from_nanos(1500),
+            verdict,
+        }
+    }
+
+    #[test]
+    fn json() {
+        let mut reporter = Reporter::new(Format::Json);
+        let art = record(Answer::Art("#.\n.#\n".to_string()), Verdict::Pass);
+        assert_eq!(
+            reporter.record(&art),
+            r##"{"day":10,"part":2,"answer":"#.\n.#","duration_ns":1500,"status":"PASS","expected":null}"##
+        );
+        let fail = record(1_u64.into(), Verdict::Fail("2".to_string()));
+        assert!(reporter.record(&fail).starts_with(",{"));
+    }
+
+    #[test]
+    fn csv() {
+        let mut reporter = Reporter::new(Format::Csv);
+        let text = record("a,b".into(), Verdict::Fail("c".to_string()));
+        assert_eq!(reporter.record(&text), "10,2,\"a,b\",1500,FAIL,c");
+    }
+}
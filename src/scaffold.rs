@@ -0,0 +1,250 @@
+// New day scaffolding
+//
+// `aoc new <day> <slug>` fills `src/problem_template.rs` in as
+// `src/days/day_NN_slug.rs`, registers it in `src/days/mod.rs`, creates an
+// empty example with a sidecar of empty answers (skipped by the example tests
+// until they're filled in), starts `puzzles/day_NN.md` for the puzzle text and
+// adds placeholder entries to the answers ledger. It refuses to touch a day
+// that already exists.
+
+use crate::answers::Ledger;
+use crate::days::find_day;
+use crate::examples::{answers_path, example_path};
+use crate::input::InputError;
+use crate::puzzles::{description_name, title_markdown};
+use std::fmt;
+use std::fs::{read_dir, read_to_string, write, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+const TEMPLATE: &str = include_str!("problem_template.rs");
+
+pub enum ScaffoldError {
+    InvalidDay(u8),
+    InvalidSlug(String),
+    Exists(String),
+    Registry(PathBuf, String),
+    Input(InputError),
+    Io(PathBuf, io::Error),
+}
+impl fmt::Display for ScaffoldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScaffoldError::InvalidDay(day) => write!(f, "Day {} is not between 1 and 25", day),
+            ScaffoldError::InvalidSlug(slug) => write!(
+                f,
+                "Slug `{}` should be lowercase words separated by `_`",
+                slug
+            ),
+            ScaffoldError::Exists(what) => write!(f, "{} already exists", what),
+            ScaffoldError::Registry(path, reason) => {
+                write!(
+                    f,
+                    "Can't register the day in {}: {}",
+                    path.display(),
+                    reason
+                )
+            }
+            ScaffoldError::Input(err) => write!(f, "{}", err),
+            ScaffoldError::Io(path, err) => write!(f, "Failed writing {}: {}", path.display(), err),
+        }
+    }
+}
+// Shown by `main`, like `ParseError`.
+impl fmt::Debug for ScaffoldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self)
+    }
+}
+impl std::error::Error for ScaffoldError {}
+impl From<InputError> for ScaffoldError {
+    fn from(err: InputError) -> Self {
+        ScaffoldError::Input(err)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NewDay {
+    pub day: u8,
+    pub slug: String,
+}
+impl NewDay {
+    pub fn new(day: u8, slug: &str) -> Result<Self, ScaffoldError> {
+        if !(1..=25).contains(&day) {
+            return Err(ScaffoldError::InvalidDay(day));
+        }
+        let valid = slug
+            .split('_')
+            .all(|word| !word.is_empty() && word.chars().all(|c| c.is_ascii_lowercase()));
+        if !valid {
+            return Err(ScaffoldError::InvalidSlug(slug.to_string()));
+        }
+        Ok(Self {
+            day,
+            slug: slug.to_string(),
+        })
+    }
+    pub fn module(&self) -> String {
+        format!("day_{:02}_{}", self.day, self.slug)
+    }
+    // `monkey_map` is titled "Monkey Map" and solved by `MonkeyMap`.
+    pub fn title(&self) -> String {
+        self.capitalized_words().join(" ")
+    }
+    pub fn type_name(&self) -> String {
+        self.capitalized_words().concat()
+    }
+    fn capitalized_words(&self) -> Vec<String> {
+        self.slug
+            .split('_')
+            .map(|word| word[..1].to_uppercase() + &word[1..])
+            .collect()
+    }
+
+    pub fn render(&self) -> String {
+        TEMPLATE
+            .replace("{NN}", &format!("{:02}", self.day))
+            .replace("{day}", &self.day.to_string())
+            .replace("{title}", &self.title())
+            .replace("{Name}", &self.type_name())
+    }
+
+    // `src/days/mod.rs` with the new module declared and added to `DAYS`,
+    // both kept in day order.
+    pub fn register(&self, registry: &str) -> Result<String, String> {
+        let module = self.module();
+        let declaration = format!("pub mod {};", module);
+        let entry = format!("    {}::DAY,", module);
+        let mut lines = registry.lines().map(String::from).collect::<Vec<String>>();
+
+        let declarations = lines
+            .iter()
+            .enumerate()
+            .filter(|(_, line)| line.starts_with("pub mod day_"))
+            .map(|(i, line)| (i, line.clone()))
+            .collect::<Vec<_>>();
+        let (last, _) = *declarations.last().ok_or("no `pub mod day_NN_...` lines")?;
+        let at = declarations
+            .iter()
+            .find(|(_, line)| *line > declaration)
+            .map_or(last + 1, |(i, _)| *i);
+        lines.insert(at, declaration);
+
+        let start = lines
+            .iter()
+            .position(|line| line.starts_with("pub static DAYS: [Day; "))
+            .ok_or("no `pub static DAYS` array")?;
+        let end = start
+            + lines[start..]
+                .iter()
+                .position(|line| line == "];")
+                .ok_or("`DAYS` isn't closed by `];`")?;
+        let at = (start + 1..end).find(|i| lines[*i] > entry).unwrap_or(end);
+        lines.insert(at, entry);
+        lines[start] = format!("pub static DAYS: [Day; {}] = [", end - start);
+
+        Ok(lines.join("\n") + "\n")
+    }
+
+    // Creates every file of the new day and returns their paths.
+    pub fn create(
+        &self,
+        src_dir: &Path,
+        puzzles_dir: &Path,
+    ) -> Result<Vec<PathBuf>, ScaffoldError> {
+        if find_day(self.day).is_some() {
+            return Err(ScaffoldError::Exists(format!("Day {}", self.day)));
+        }
+        let days_dir = src_dir.join("days");
+        let prefix = format!("day_{:02}_", self.day);
+        let existing = read_dir(&days_dir)
+            .map_err(|err| ScaffoldError::Io(days_dir.clone(), err))?
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .find(|path| {
+                path.file_name()
+                    .and_then(|name| name.to_str())
+                    .is_some_and(|name| name.starts_with(&prefix))
+            });
+        if let Some(path) = existing {
+            return Err(ScaffoldError::Exists(path.display().to_string()));
+        }
+        let example = example_path(self.day, 1);
+        let answers = answers_path(self.day, 1);
+        let description = puzzles_dir.join(description_name(self.day));
+        for path in [&example, &answers, &description] {
+            if path.exists() {
+                return Err(ScaffoldError::Exists(path.display().to_string()));
+            }
+        }
+        let registry_path = days_dir.join("mod.rs");
+        let registry = read_to_string(&registry_path)
+            .map_err(|err| ScaffoldError::Io(registry_path.clone(), err))?;
+        let registry = self
+            .register(&registry)
+            .map_err(|reason| ScaffoldError::Registry(registry_path.clone(), reason))?;
+
+        let module = days_dir.join(format!("{}.rs", self.module()));
+        create_new(&module, &self.render())?;
+        create_new(&example, "")?;
+        create_new(&answers, "1: \n2: \n")?;
+        create_new(&description, &title_markdown(self.day, &self.title()))?;
+        write(&registry_path, registry)
+            .map_err(|err| ScaffoldError::Io(registry_path.clone(), err))?;
+        let ledger_path = Ledger::path();
+        let mut ledger = Ledger::load(&ledger_path)?;
+        ledger.add_day(self.day);
+        ledger.save(&ledger_path)?;
+        Ok(vec![
+            module,
+            registry_path,
+            example,
+            answers,
+            description,
+            ledger_path,
+        ])
+    }
+}
+
+fn create_new(path: &Path, contents: &str) -> Result<(), ScaffoldError> {
+    OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(path)
+        .and_then(|mut file| file.write_all(contents.as_bytes()))
+        .map_err(|err| match err.kind() {
+            io::ErrorKind::AlreadyExists => ScaffoldError::Exists(path.display().to_string()),
+            _ => ScaffoldError::Io(path.to_path_buf(), err),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn names_and_template() {
+        let new_day = NewDay::new(24, "blizzard_basin").unwrap();
+        assert_eq!(new_day.module(), "day_24_blizzard_basin");
+        assert_eq!(new_day.title(), "Blizzard Basin");
+        let source = new_day.render();
+        assert!(source.contains("https://adventofcode.com/2022/day/24\n"));
+        assert!(source.contains("data/day_24_input.txt"));
+        assert!(source.contains("Day::new::<BlizzardBasin>(24, \"Blizzard Basin\")"));
+        assert!(!["{NN}", "{day}", "{title}", "{Name}"]
+            .iter()
+            .any(|placeholder| source.contains(placeholder)));
+        assert!(NewDay::new(26, "too_late").is_err());
+        assert!(NewDay::new(24, "Blizzard-Basin").is_err());
+    }
+
+    #[test]
+    fn registers_in_day_order() {
+        let registry = "pub mod day_01_a;\npub mod day_03_c;\n\npub static DAYS: [Day; 2] = [\n    day_01_a::DAY,\n    day_03_c::DAY,\n];\n";
+        let registry = NewDay::new(2, "b").unwrap().register(registry).unwrap();
+        assert_eq!(
+            registry,
+            "pub mod day_01_a;\npub mod day_02_b;\npub mod day_03_c;\n\npub static DAYS: [Day; 3] = [\n    day_01_a::DAY,\n    day_02_b::DAY,\n    day_03_c::DAY,\n];\n"
+        );
+    }
+}
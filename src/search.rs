@@ -0,0 +1,219 @@
+// Graph search
+//
+// BFS, Dijkstra and A* over anything implementing `Graph`. Searches stop at
+// the first node accepted by `is_goal` and return its cost together with the
+// path that got there, start and goal included. For one-off graphs `from_fn`
+// wraps a closure returning the neighbours of a node.
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+use std::marker::PhantomData;
+
+pub trait Graph {
+    type Node: Clone + Eq + Hash;
+
+    // Nodes one step away, each with the cost of the step. BFS counts steps
+    // and ignores the costs.
+    fn neighbours(&self, node: &Self::Node) -> impl IntoIterator<Item = (Self::Node, usize)>;
+}
+
+pub struct FnGraph<N, F> {
+    neighbours: F,
+    node: PhantomData<fn(&N)>,
+}
+impl<N, F, I> Graph for FnGraph<N, F>
+where
+    N: Clone + Eq + Hash,
+    F: Fn(&N) -> I,
+    I: IntoIterator<Item = (N, usize)>,
+{
+    type Node = N;
+
+    fn neighbours(&self, node: &N) -> impl IntoIterator<Item = (N, usize)> {
+        (self.neighbours)(node)
+    }
+}
+
+pub fn from_fn<N, F, I>(neighbours: F) -> FnGraph<N, F>
+where
+    F: Fn(&N) -> I,
+    I: IntoIterator<Item = (N, usize)>,
+{
+    FnGraph {
+        neighbours,
+        node: PhantomData,
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<N> {
+    pub cost: usize,
+    pub nodes: Vec<N>,
+}
+
+pub fn bfs<G: Graph>(
+    graph: &G,
+    start: G::Node,
+    is_goal: impl FnMut(&G::Node) -> bool,
+) -> Option<Path<G::Node>> {
+    bfs_multi(graph, [start], is_goal)
+}
+
+// BFS from several starts at once: the path begins at whichever start is
+// closest to a goal.
+pub fn bfs_multi<G: Graph>(
+    graph: &G,
+    starts: impl IntoIterator<Item = G::Node>,
+    mut is_goal: impl FnMut(&G::Node) -> bool,
+) -> Option<Path<G::Node>> {
+    let mut parents: HashMap<G::Node, Option<G::Node>> = HashMap::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if !parents.contains_key(&start) {
+            parents.insert(start.clone(), None);
+            queue.push_back((start, 0));
+        }
+    }
+    while let Some((node, steps)) = queue.pop_front() {
+        if is_goal(&node) {
+            return Some(Path {
+                cost: steps,
+                nodes: reconstruct(node, |n| parents[n].as_ref()),
+            });
+        }
+        for (next, _) in graph.neighbours(&node) {
+            if !parents.contains_key(&next) {
+                parents.insert(next.clone(), Some(node.clone()));
+                queue.push_back((next, steps + 1));
+            }
+        }
+    }
+    None
+}
+
+// Steps from `start` to every reachable node.
+pub fn distances<G: Graph>(graph: &G, start: G::Node) -> HashMap<G::Node, usize> {
+    let mut distances = HashMap::from([(start.clone(), 0)]);
+    let mut queue = VecDeque::from([(start, 0)]);
+    while let Some((node, steps)) = queue.pop_front() {
+        for (next, _) in graph.neighbours(&node) {
+            if !distances.contains_key(&next) {
+                distances.insert(next.clone(), steps + 1);
+                queue.push_back((next, steps + 1));
+            }
+        }
+    }
+    distances
+}
+
+pub fn dijkstra<G: Graph>(
+    graph: &G,
+    start: G::Node,
+    is_goal: impl FnMut(&G::Node) -> bool,
+) -> Option<Path<G::Node>> {
+    astar(graph, start, is_goal, |_| 0)
+}
+
+// `heuristic` must never overestimate the remaining cost to a goal.
+pub fn astar<G: Graph>(
+    graph: &G,
+    start: G::Node,
+    mut is_goal: impl FnMut(&G::Node) -> bool,
+    mut heuristic: impl FnMut(&G::Node) -> usize,
+) -> Option<Path<G::Node>> {
+    // The heap holds indexes into `seen` so nodes don't need to be `Ord`.
+    let mut best: HashMap<G::Node, (usize, Option<G::Node>)> = HashMap::new();
+    let mut seen = vec![start.clone()];
+    let mut heap = BinaryHeap::from([Reverse((heuristic(&start), 0, 0))]);
+    best.insert(start, (0, None));
+    while let Some(Reverse((_, cost, index))) = heap.pop() {
+        let node = seen[index].clone();
+        if best[&node].0 < cost {
+            continue;
+        }
+        if is_goal(&node) {
+            return Some(Path {
+                cost,
+                nodes: reconstruct(node, |n| best[n].1.as_ref()),
+            });
+        }
+        for (next, step) in graph.neighbours(&node) {
+            let next_cost = cost + step;
+            if best
+                .get(&next)
+                .is_some_and(|(known, _)| *known <= next_cost)
+            {
+                continue;
+            }
+            best.insert(next.clone(), (next_cost, Some(node.clone())));
+            heap.push(Reverse((
+                next_cost + heuristic(&next),
+                next_cost,
+                seen.len(),
+            )));
+            seen.push(next);
+        }
+    }
+    None
+}
+
+fn reconstruct<'a, N: Clone + 'a>(goal: N, parent: impl Fn(&N) -> Option<&'a N>) -> Vec<N> {
+    let mut nodes = vec![goal];
+    while let Some(previous) = parent(nodes.last().unwrap()) {
+        nodes.push(previous.clone());
+    }
+    nodes.reverse();
+    nodes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 0 - 1 - 2 - 3 with a costly shortcut 0 - 3.
+    fn line(node: &u8) -> Vec<(u8, usize)> {
+        let mut neighbours = vec![];
+        if *node > 0 {
+            neighbours.push((node - 1, 1));
+        }
+        if *node < 3 {
+            neighbours.push((node + 1, 1));
+        }
+        match node {
+            0 => neighbours.push((3, 10)),
+            3 => neighbours.push((0, 10)),
+            _ => (),
+        }
+        neighbours
+    }
+
+    #[test]
+    fn bfs_counts_steps() {
+        let graph = from_fn(line);
+        let path = bfs(&graph, 0, |n| *n == 3).unwrap();
+        assert_eq!(
+            path,
+            Path {
+                cost: 1,
+                nodes: vec![0, 3]
+            }
+        );
+        let path = bfs_multi(&graph, [1, 2], |n| *n == 3).unwrap();
+        assert_eq!(path.nodes, vec![2, 3]);
+        assert_eq!(distances(&graph, 1)[&3], 2);
+    }
+
+    #[test]
+    fn weighted_searches() {
+        let graph = from_fn(line);
+        let expected = Path {
+            cost: 3,
+            nodes: vec![0, 1, 2, 3],
+        };
+        assert_eq!(dijkstra(&graph, 0, |n| *n == 3).unwrap(), expected);
+        let heuristic = |n: &u8| 3 - *n as usize;
+        assert_eq!(astar(&graph, 0, |n| *n == 3, heuristic).unwrap(), expected);
+        assert_eq!(dijkstra(&graph, 0, |n| *n == 4), None);
+    }
+}
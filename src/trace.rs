@@ -0,0 +1,168 @@
+// Tracing
+//
+// Days explain what they're doing with `trace!(Debug, "...")` and friends,
+// which prints nothing unless the filter asks for it. `aoc run --trace` sets
+// the filter from a list like `debug` (every day), `12` (everything from day
+// 12) or `12=trace,19=info`. Traces go to stderr, so the answers on stdout
+// stay clean.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::RwLock;
+
+// From the least to the most verbose.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Info,
+    Debug,
+    Trace,
+}
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Level::Info => write!(f, "INFO"),
+            Level::Debug => write!(f, "DEBUG"),
+            Level::Trace => write!(f, "TRACE"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct ParseFilterError(String);
+impl fmt::Display for ParseFilterError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Trace filter {} not understood, use a level (info, debug or trace), a day or day=level",
+            self.0
+        )
+    }
+}
+impl std::error::Error for ParseFilterError {}
+
+impl FromStr for Level {
+    type Err = ParseFilterError;
+
+    fn from_str(level: &str) -> Result<Self, Self::Err> {
+        match level {
+            "info" => Ok(Level::Info),
+            "debug" => Ok(Level::Debug),
+            "trace" => Ok(Level::Trace),
+            other => Err(ParseFilterError(other.to_string())),
+        }
+    }
+}
+
+// The most verbose level shown for every day, and for some days in particular.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Filter {
+    all: Option<Level>,
+    days: BTreeMap<u8, Level>,
+}
+impl Filter {
+    pub const fn off() -> Self {
+        Self {
+            all: None,
+            days: BTreeMap::new(),
+        }
+    }
+    pub fn is_off(&self) -> bool {
+        self.all.is_none() && self.days.is_empty()
+    }
+    pub fn enabled(&self, level: Level, day: Option<u8>) -> bool {
+        day.and_then(|day| self.days.get(&day))
+            .or(self.all.as_ref())
+            .is_some_and(|max| level <= *max)
+    }
+}
+
+impl FromStr for Filter {
+    type Err = ParseFilterError;
+
+    fn from_str(filter: &str) -> Result<Self, Self::Err> {
+        let mut parsed = Filter::off();
+        for directive in filter.split(',').map(str::trim) {
+            let error = || ParseFilterError(directive.to_string());
+            let (day, level) = match directive.split_once('=') {
+                Some((day, level)) => (Some(day), level.parse()?),
+                None if directive.starts_with(|c: char| c.is_ascii_digit()) => {
+                    (Some(directive), Level::Trace)
+                }
+                None => (None, directive.parse()?),
+            };
+            match day {
+                Some(day) => {
+                    let day = day.parse().map_err(|_| error())?;
+                    parsed.days.insert(day, level);
+                }
+                None => parsed.all = Some(level),
+            }
+        }
+        Ok(parsed)
+    }
+}
+
+// Checked first so that tracing costs next to nothing when it's off.
+static ON: AtomicBool = AtomicBool::new(false);
+static FILTER: RwLock<Filter> = RwLock::new(Filter::off());
+
+pub fn set_filter(filter: Filter) {
+    ON.store(!filter.is_off(), Ordering::Relaxed);
+    *FILTER.write().unwrap() = filter;
+}
+
+// The day of a module like `aoc2022::days::day_12_hill_climbing_algorithm`.
+fn day_of(module: &str) -> Option<u8> {
+    module
+        .rsplit("::")
+        .find_map(|name| name.strip_prefix("day_"))
+        .and_then(|name| name.get(..2)?.parse().ok())
+}
+
+pub fn enabled(level: Level, module: &str) -> bool {
+    ON.load(Ordering::Relaxed) && FILTER.read().unwrap().enabled(level, day_of(module))
+}
+
+pub fn emit(level: Level, module: &str, message: fmt::Arguments) {
+    match day_of(module) {
+        Some(day) => eprintln!("[day {:02} {}] {}", day, level, message),
+        None => eprintln!("[{} {}] {}", module, level, message),
+    }
+}
+
+// `trace!(Debug, "{} left", count)` prints the message when the filter shows
+// debug messages for the calling day. The arguments aren't even evaluated
+// otherwise.
+#[macro_export]
+macro_rules! trace {
+    ($level:ident, $($arg:tt)+) => {
+        if $crate::trace::enabled($crate::trace::Level::$level, module_path!()) {
+            $crate::trace::emit(
+                $crate::trace::Level::$level,
+                module_path!(),
+                format_args!($($arg)+),
+            );
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn filters() {
+        let filter = "debug,12,19=info".parse::<Filter>().unwrap();
+        assert!(filter.enabled(Level::Debug, None));
+        assert!(!filter.enabled(Level::Trace, Some(9)));
+        assert!(filter.enabled(Level::Trace, Some(12)));
+        assert!(!filter.enabled(Level::Debug, Some(19)));
+        assert!(!Filter::off().enabled(Level::Info, Some(12)));
+        assert!("12=loud".parse::<Filter>().is_err());
+        assert!("day=info".parse::<Filter>().is_err());
+        assert_eq!(day_of("aoc2022::days::day_09_rope_bridge"), Some(9));
+        assert_eq!(day_of("aoc2022::search"), None);
+    }
+}
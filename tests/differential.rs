@@ -0,0 +1,42 @@
+// Fast solutions against their reference solutions (see `src/reference.rs`)
+
+use aoc2022::days::DAYS;
+use aoc2022::reference::{differential, DiffOptions};
+
+// Day 15 searches a 4000000 wide square either way, which takes a release
+// build: `cargo test --release -- --ignored`.
+const SLOW_DAYS: [u8; 1] = [15];
+
+fn agree(days: &[u8], options: DiffOptions) {
+    for day in DAYS.iter().filter(|day| days.contains(&day.day)) {
+        if let Some(disagreement) = differential(day, options) {
+            panic!("{}", disagreement);
+        }
+    }
+}
+
+#[test]
+fn solutions_agree_with_their_references() {
+    let days = DAYS
+        .iter()
+        .map(|day| day.day)
+        .filter(|day| !SLOW_DAYS.contains(day))
+        .collect::<Vec<u8>>();
+    let options = DiffOptions {
+        runs: 30,
+        size: 8,
+        ..DiffOptions::default()
+    };
+    agree(&days, options);
+}
+
+#[test]
+#[ignore]
+fn slow_solutions_agree_with_their_references() {
+    let options = DiffOptions {
+        runs: 3,
+        size: 3,
+        ..DiffOptions::default()
+    };
+    agree(&SLOW_DAYS, options);
+}
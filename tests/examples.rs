@@ -0,0 +1,3 @@
+// Puzzle examples from `data/`, one test per example (see `build.rs`)
+
+include!(concat!(env!("OUT_DIR"), "/example_tests.rs"));
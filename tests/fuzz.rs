@@ -0,0 +1,42 @@
+// Generated inputs and parser fuzzing for every day (see `src/fuzz.rs`)
+
+use aoc2022::days::DAYS;
+use aoc2022::fuzz::{self, crashers, try_parse, FuzzOptions};
+use aoc2022::generate::Rng;
+
+#[test]
+fn generated_inputs_round_trip() {
+    for day in DAYS.iter() {
+        let mut rng = Rng::new(2022);
+        for size in 1..=20 {
+            if let Err(err) = (day.round_trip)(&mut rng, size) {
+                panic!("Day {} with size {}: {}", day.day, size, err);
+            }
+        }
+    }
+}
+
+#[test]
+fn parsers_survive_mangled_inputs() {
+    let options = FuzzOptions {
+        runs: 300,
+        ..FuzzOptions::default()
+    };
+    for day in DAYS.iter() {
+        if let Some(crash) = fuzz::fuzz(day, options).first() {
+            panic!(
+                "Day {} panicked with {:?} on\n{}",
+                day.day, crash.message, crash.input
+            );
+        }
+    }
+}
+
+#[test]
+fn saved_crashers_are_fixed() {
+    for (day, path, input) in crashers().unwrap() {
+        if let Err(message) = try_parse(day, &input) {
+            panic!("{} still panics: {}", path.display(), message);
+        }
+    }
+}
@@ -0,0 +1,33 @@
+// A freshly scaffolded day (see `src/scaffold.rs`) against the example suite
+
+use aoc2022::examples::{assert_example, find_examples};
+use aoc2022::input::DATA_DIR_ENV;
+use aoc2022::scaffold::NewDay;
+use std::env;
+use std::fs::{copy, create_dir_all, remove_dir_all};
+use std::path::Path;
+
+// Its own test binary: it points `AOC_DATA_DIR` at a scratch directory, which
+// would move every other test's inputs too.
+#[test]
+fn new_day_examples_pass_until_filled_in() {
+    let dir = env::temp_dir().join("aoc2022_scaffold_tests");
+    let _ = remove_dir_all(&dir);
+    let (src_dir, data_dir) = (dir.join("src"), dir.join("data"));
+    create_dir_all(src_dir.join("days")).unwrap();
+    create_dir_all(&data_dir).unwrap();
+    create_dir_all(dir.join("puzzles")).unwrap();
+    let registry = Path::new(env!("CARGO_MANIFEST_DIR")).join("src/days/mod.rs");
+    copy(registry, src_dir.join("days/mod.rs")).unwrap();
+    env::set_var(DATA_DIR_ENV, &data_dir);
+
+    NewDay::new(24, "blizzard_basin")
+        .unwrap()
+        .create(&src_dir, &dir.join("puzzles"))
+        .unwrap();
+    let examples = find_examples(24).unwrap();
+    assert_eq!(examples.len(), 1);
+    for example in examples {
+        assert_example(example.day, example.index);
+    }
+}